@@ -0,0 +1,155 @@
+use crate::{Error, Result};
+
+/// An 8 or 11 character Business Identifier Code as defined in
+/// ISO 9362, also known as SWIFT code.
+///
+/// A BIC consists of a 4 character party prefix, a 2 letter country
+/// code, a 2 character party suffix and an optional 3 character branch
+/// code. Only the structure is validated, as BICs carry no checksum.
+/// <https://www.swift.com/standards/data-standards/bic-business-identifier-code>
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(transparent)]
+pub struct BIC {
+    bic: String,
+}
+
+impl<'de> serde::Deserialize<'de> for BIC {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> std::result::Result<Self, D::Error> {
+        let string: String = serde::Deserialize::deserialize(d)?;
+        string.as_str().try_into().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+sqlx_text!(BIC);
+
+impl std::fmt::Display for BIC {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.bic.fmt(f)
+    }
+}
+
+impl TryFrom<&str> for BIC {
+    type Error = Error;
+    fn try_from(from: &str) -> Result<Self> {
+        if from.len() != 8 && from.len() != 11 {
+            return Err(Error::InvalidBicLength(from.len()));
+        }
+        for (pos, char) in from.chars().enumerate() {
+            let valid = match pos {
+                // The country code
+                4 | 5 => char.is_ascii_uppercase(),
+                _ => char.is_ascii_uppercase() || char.is_ascii_digit(),
+            };
+            if !valid {
+                return Err(Error::InvalidChar { pos, char });
+            }
+        }
+        Ok(Self { bic: from.into() })
+    }
+}
+
+impl BIC {
+    /// Returns the party prefix, formerly known as institution code.
+    pub fn party_prefix(&self) -> &str {
+        &self.bic[..4]
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 country code.
+    pub fn country_code(&self) -> &str {
+        &self.bic[4..6]
+    }
+
+    /// Returns the party suffix, formerly known as location code.
+    pub fn party_suffix(&self) -> &str {
+        &self.bic[6..8]
+    }
+
+    /// Returns the branch code, if present. The branch code `XXX`
+    /// denotes the primary office.
+    pub fn branch_code(&self) -> Option<&str> {
+        self.bic.get(8..).filter(|branch| !branch.is_empty())
+    }
+
+    /// Returns `true` if this BIC identifies the primary office of the
+    /// party, i.e. it has no branch code or the branch code `XXX`.
+    pub fn is_primary_office(&self) -> bool {
+        matches!(self.branch_code(), None | Some("XXX"))
+    }
+
+    /// Returns the 11 character form of this BIC, using the branch code
+    /// `XXX` if none is present.
+    pub fn to_bic11(&self) -> Self {
+        Self {
+            bic: format!("{:X<11}", self.bic),
+        }
+    }
+
+    /// Returns the 8 character form of this BIC, identifying the
+    /// party without a particular branch.
+    pub fn to_bic8(&self) -> Self {
+        Self {
+            bic: self.bic[..8].into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[test]
+    fn test_happy_parse() {
+        let bic = BIC::try_from("DEUTDEFF").unwrap();
+        assert_eq!(bic.party_prefix(), "DEUT");
+        assert_eq!(bic.country_code(), "DE");
+        assert_eq!(bic.party_suffix(), "FF");
+        assert_eq!(bic.branch_code(), None);
+        assert!(bic.is_primary_office());
+
+        let bic = BIC::try_from("DEUTDEFF500").unwrap();
+        assert_eq!(bic.branch_code(), Some("500"));
+        assert!(!bic.is_primary_office());
+        assert_eq!(bic.to_bic8().to_string(), "DEUTDEFF");
+
+        let bic = BIC::try_from("UBSWCHZH80A").unwrap();
+        assert_eq!(bic.country_code(), "CH");
+        assert_eq!(
+            BIC::try_from("UBSWCHZH").unwrap().to_bic11().to_string(),
+            "UBSWCHZHXXX"
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        for bic in ["", "DEUTDEF", "DEUTDEFF5", "DEUTDEFF5000"] {
+            assert_eq!(
+                BIC::try_from(bic).unwrap_err(),
+                Error::InvalidBicLength(bic.len())
+            );
+        }
+        assert_eq!(
+            BIC::try_from("DEUT1EFF").unwrap_err(),
+            Error::InvalidChar { pos: 4, char: '1' }
+        );
+        assert_eq!(
+            BIC::try_from("deutdeff").unwrap_err(),
+            Error::InvalidChar { pos: 0, char: 'd' }
+        );
+        assert_eq!(
+            BIC::try_from("DEUTDEFF-00").unwrap_err(),
+            Error::InvalidChar { pos: 8, char: '-' }
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let bic = BIC::try_from("DEUTDEFF500").unwrap();
+        assert_tokens(&bic, &[Token::Str("DEUTDEFF500")]);
+        assert_de_tokens_error::<BIC>(
+            &[Token::Str("DEUTDEFF5")],
+            "invalid BIC length: 9, expected 8 or 11",
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use crate::LEI;
+
+/// The errors emitted when validating a CSV file.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The file is not valid CSV.
+    #[error("invalid CSV: {0}")]
+    Csv(#[from] csv::Error),
+    /// The LEI column is missing.
+    #[error("missing column: {0}")]
+    MissingColumn(String),
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// The validation result of a single CSV row.
+#[derive(Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Row {
+    /// The line of the row in the CSV file.
+    pub line: u64,
+    /// The value of the LEI column, with surrounding whitespace removed.
+    pub value: String,
+    /// The validation error, if the value is not a valid LEI.
+    #[serde(rename = "errorType", serialize_with = "serialize_error_kind")]
+    pub error: Option<crate::Error>,
+    /// All fields of the row.
+    #[serde(skip)]
+    pub record: csv::StringRecord,
+}
+
+fn serialize_error_kind<S: serde::Serializer>(
+    error: &Option<crate::Error>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match error {
+        Some(error) => serializer.serialize_some(error.kind()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// The number of valid and invalid rows of a CSV file.
+#[derive(Debug, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Summary {
+    /// The number of rows.
+    pub total: usize,
+    /// The number of rows with a valid LEI.
+    pub valid: usize,
+    /// The number of rows with an invalid LEI.
+    pub invalid: usize,
+    /// The number of invalid rows by error kind, see
+    /// [`Error::kind`](crate::Error::kind).
+    pub errors: BTreeMap<&'static str, usize>,
+}
+
+/// The validation report of a CSV file.
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub struct Report {
+    /// The summary counts.
+    pub summary: Summary,
+    /// The results for every row, in file order.
+    pub rows: Vec<Row>,
+    #[serde(skip)]
+    headers: csv::StringRecord,
+}
+
+/// Validates the LEIs in the column named `column` of a CSV file. The
+/// column name is matched case-insensitively.
+///
+/// ```
+/// let csv = "Name,LEI\nUBS,BFM8T61CT2L1QCEMIK50\nTypo,BFM8T61CT2L1QCEMIK51\n";
+/// let report = leim::bulk::validate(csv.as_bytes(), "lei").unwrap();
+/// assert_eq!(report.summary.valid, 1);
+/// assert_eq!(report.summary.errors["invalid_checksum"], 1);
+/// ```
+pub fn validate<R: Read>(reader: R, column: &str) -> Result<Report> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+    let headers = reader.headers()?.clone();
+    let index = headers
+        .iter()
+        .position(|header| header.eq_ignore_ascii_case(column))
+        .ok_or_else(|| Error::MissingColumn(column.into()))?;
+
+    let mut summary = Summary::default();
+    let mut rows = Vec::new();
+    for record in reader.into_records() {
+        let record = record?;
+        let value = record.get(index).unwrap_or_default().trim().to_string();
+        let error = LEI::try_from(value.as_str()).err();
+        summary.total += 1;
+        match &error {
+            Some(error) => {
+                summary.invalid += 1;
+                *summary.errors.entry(error.kind()).or_default() += 1;
+            }
+            None => summary.valid += 1,
+        }
+        rows.push(Row {
+            line: record.position().map_or(0, csv::Position::line),
+            value,
+            error,
+            record,
+        });
+    }
+    Ok(Report {
+        summary,
+        rows,
+        headers,
+    })
+}
+
+impl Report {
+    /// Returns whether all rows contain a valid LEI.
+    pub fn is_valid(&self) -> bool {
+        self.summary.invalid == 0
+    }
+
+    /// Writes the rows as CSV, annotated with the columns `lei_valid`,
+    /// `lei_error_type` and `lei_error`.
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<()> {
+        let mut writer = csv::Writer::from_writer(writer);
+        let mut headers = self.headers.clone();
+        headers.extend(["lei_valid", "lei_error_type", "lei_error"]);
+        writer.write_record(&headers)?;
+        for row in &self.rows {
+            let mut record = row.record.clone();
+            // Pad short rows so the annotations line up.
+            for _ in record.len()..self.headers.len() {
+                record.push_field("");
+            }
+            match &row.error {
+                Some(error) => record.extend(["false", error.kind(), &error.to_string()]),
+                None => record.extend(["true", "", ""]),
+            }
+            writer.write_record(&record)?;
+        }
+        writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNTERPARTIES: &str = "\
+Name,LEI,Country
+UBS, 5493000IBP32UQZ0KL24 ,CH
+Typo,5493000IBP32UQZ0KL25,CH
+Short,5493000IBP32UQZ0KL,DE
+Missing
+";
+
+    #[test]
+    fn test_validate() {
+        let report = validate(COUNTERPARTIES.as_bytes(), "lei").unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.summary,
+            Summary {
+                total: 4,
+                valid: 1,
+                invalid: 3,
+                errors: [("invalid_checksum", 1), ("invalid_length", 2)].into(),
+            }
+        );
+        let rows: Vec<_> = report
+            .rows
+            .iter()
+            .map(|row| (row.line, row.value.as_str(), row.error.as_ref()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                (2, "5493000IBP32UQZ0KL24", None),
+                (
+                    3,
+                    "5493000IBP32UQZ0KL25",
+                    Some(&crate::Error::InvalidChecksum)
+                ),
+                (
+                    4,
+                    "5493000IBP32UQZ0KL",
+                    Some(&crate::Error::InvalidLength(18))
+                ),
+                (5, "", Some(&crate::Error::InvalidLength(0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_chars() {
+        let csv = "LEI\n5493000IBP32UQ#0KL24\n5493000ibp32uqz0kl24\n";
+        let report = validate(csv.as_bytes(), "LEI").unwrap();
+        assert_eq!(report.summary.errors, [("invalid_char", 2)].into());
+        let errors: Vec<_> = report.rows.iter().map(|row| row.error.as_ref()).collect();
+        assert_eq!(
+            errors,
+            [
+                Some(&crate::Error::InvalidChar { pos: 14, char: '#' }),
+                Some(&crate::Error::InvalidChar { pos: 7, char: 'i' }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let report = validate(COUNTERPARTIES.as_bytes(), "LEI").unwrap();
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "\
+Name,LEI,Country,lei_valid,lei_error_type,lei_error
+UBS, 5493000IBP32UQZ0KL24 ,CH,true,,
+Typo,5493000IBP32UQZ0KL25,CH,false,invalid_checksum,invalid checksum
+Short,5493000IBP32UQZ0KL,DE,false,invalid_length,\"invalid length: 18, expected 20\"
+Missing,,,false,invalid_length,\"invalid length: 0, expected 20\"
+"
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        let report = validate(COUNTERPARTIES.as_bytes(), "LEI").unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["summary"]["invalid"], 3);
+        assert_eq!(
+            json["rows"][1],
+            serde_json::json!({
+                "line": 3,
+                "value": "5493000IBP32UQZ0KL25",
+                "errorType": "invalid_checksum",
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_column() {
+        assert!(matches!(
+            validate(COUNTERPARTIES.as_bytes(), "BIC"),
+            Err(Error::MissingColumn(column)) if column == "BIC"
+        ));
+    }
+}
@@ -0,0 +1,248 @@
+// The request building and response decoding lives in this module and
+// is shared by the concrete clients, which only perform the HTTP round
+// trip.
+
+use crate::record::LeiRecord;
+use crate::LEI;
+
+/// A blocking client for the GLEIF API.
+pub mod blocking;
+
+/// The base URL of the public GLEIF API.
+pub const DEFAULT_BASE_URL: &str = "https://api.gleif.org/api/v1";
+
+/// The errors emitted by the GLEIF API clients.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// The HTTP request could not be performed.
+    #[error("transport error: {0}")]
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+    /// The API responded with an unexpected HTTP status.
+    #[error("unexpected HTTP status: {0}")]
+    Status(u16),
+    /// The API response could not be decoded.
+    #[error("invalid response: {0}")]
+    Decode(#[from] serde_json::Error),
+    /// The API response contained an invalid LEI or status.
+    #[error("invalid value in response: {0}")]
+    Invalid(#[from] crate::Error),
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// The GLEIF API endpoints, independent of any HTTP implementation.
+#[derive(Clone, Debug)]
+struct Api {
+    base_url: String,
+}
+
+impl Api {
+    fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+        }
+    }
+
+    fn lei_record_url(&self, lei: &LEI) -> String {
+        format!("{}/lei-records/{lei}", self.base_url)
+    }
+
+    fn lei_records_url(&self, leis: &[LEI]) -> String {
+        let leis = leis
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{}/lei-records?filter[lei]={leis}&page[size]={}",
+            self.base_url, MAX_PAGE_SIZE
+        )
+    }
+
+    fn decode_lei_record(status: u16, body: &[u8]) -> Result<Option<LeiRecord>> {
+        match status {
+            200 => {
+                let document: Document<Resource> = serde_json::from_slice(body)?;
+                Ok(Some(document.data.try_into()?))
+            }
+            404 => Ok(None),
+            status => Err(Error::Status(status)),
+        }
+    }
+
+    fn decode_lei_records(status: u16, body: &[u8]) -> Result<Vec<LeiRecord>> {
+        match status {
+            200 => {
+                let document: Document<Vec<Resource>> = serde_json::from_slice(body)?;
+                document
+                    .data
+                    .into_iter()
+                    .map(|resource| Ok(resource.try_into()?))
+                    .collect()
+            }
+            status => Err(Error::Status(status)),
+        }
+    }
+}
+
+/// The largest page size the GLEIF API accepts.
+const MAX_PAGE_SIZE: usize = 200;
+
+// The JSON:API document structure returned by the GLEIF API. Only the
+// fields needed to construct a `LeiRecord` are decoded.
+
+#[derive(serde::Deserialize)]
+struct Document<T> {
+    data: T,
+}
+
+#[derive(serde::Deserialize)]
+struct Resource {
+    attributes: Attributes,
+}
+
+#[derive(serde::Deserialize)]
+struct Attributes {
+    lei: String,
+    entity: Entity,
+    registration: Registration,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Entity {
+    legal_name: Name,
+    jurisdiction: Option<String>,
+    status: String,
+    successor_entity: Option<SuccessorEntity>,
+}
+
+#[derive(serde::Deserialize)]
+struct Name {
+    name: String,
+}
+
+#[derive(serde::Deserialize)]
+struct SuccessorEntity {
+    lei: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Registration {
+    status: String,
+    managing_lou: String,
+}
+
+impl TryFrom<Resource> for LeiRecord {
+    type Error = crate::Error;
+    fn try_from(resource: Resource) -> std::result::Result<Self, Self::Error> {
+        let Attributes {
+            lei,
+            entity,
+            registration,
+        } = resource.attributes;
+        Ok(Self {
+            lei: lei.as_str().try_into()?,
+            legal_name: entity.legal_name.name,
+            jurisdiction: entity.jurisdiction,
+            entity_status: entity.status.as_str().try_into()?,
+            registration_status: registration.status.as_str().try_into()?,
+            managing_lou: registration.managing_lou.as_str().try_into()?,
+            successor_lei: entity
+                .successor_entity
+                .and_then(|successor| successor.lei)
+                .map(|lei| lei.as_str().try_into())
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::status::{EntityStatus, RegistrationStatus};
+
+    /// An abbreviated GLEIF API resource for a single LEI record.
+    pub(crate) const LEI_RESOURCE: &str = r#"{
+        "type": "lei-records",
+        "id": "5493000IBP32UQZ0KL24",
+        "attributes": {
+            "lei": "5493000IBP32UQZ0KL24",
+            "entity": {
+                "legalName": { "name": "Example AG", "language": "de" },
+                "jurisdiction": "CH",
+                "status": "ACTIVE",
+                "successorEntity": { "lei": null, "name": null }
+            },
+            "registration": {
+                "status": "ISSUED",
+                "managingLou": "5299000J2N45DDNE4Y28"
+            }
+        }
+    }"#;
+
+    pub(crate) fn lei_record_document() -> String {
+        format!(r#"{{ "data": {LEI_RESOURCE} }}"#)
+    }
+
+    pub(crate) fn lei_records_document() -> String {
+        format!(r#"{{ "data": [{LEI_RESOURCE}] }}"#)
+    }
+
+    #[test]
+    fn test_urls() {
+        let api = Api::new("http://localhost/api/v1/");
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert_eq!(
+            api.lei_record_url(&lei),
+            "http://localhost/api/v1/lei-records/5493000IBP32UQZ0KL24"
+        );
+        let other = LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        assert_eq!(
+            api.lei_records_url(&[lei, other]),
+            "http://localhost/api/v1/lei-records?filter[lei]=5493000IBP32UQZ0KL24,2594007XIACKNMUAW223&page[size]=200"
+        );
+    }
+
+    #[test]
+    fn test_decode_lei_record() {
+        let record = Api::decode_lei_record(200, lei_record_document().as_bytes())
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.lei.to_string(), "5493000IBP32UQZ0KL24");
+        assert_eq!(record.legal_name, "Example AG");
+        assert_eq!(record.jurisdiction.as_deref(), Some("CH"));
+        assert_eq!(record.entity_status, EntityStatus::Active);
+        assert_eq!(record.registration_status, RegistrationStatus::Issued);
+        assert_eq!(record.successor_lei, None);
+    }
+
+    #[test]
+    fn test_decode_lei_records() {
+        let records = Api::decode_lei_records(200, lei_records_document().as_bytes()).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].legal_name, "Example AG");
+        assert!(Api::decode_lei_records(200, br#"{ "data": [] }"#)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert!(Api::decode_lei_record(404, b"").unwrap().is_none());
+        assert!(matches!(
+            Api::decode_lei_record(500, b""),
+            Err(Error::Status(500))
+        ));
+        assert!(matches!(
+            Api::decode_lei_record(200, b"{}"),
+            Err(Error::Decode(_))
+        ));
+        assert!(matches!(
+            Api::decode_lei_record(200, lei_record_document().replace("L24", "L25").as_bytes()),
+            Err(Error::Invalid(crate::Error::InvalidChecksum))
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+use std::io::Read;
+
+use super::{Api, Error, Result};
+use crate::record::LeiRecord;
+use crate::LEI;
+
+/// The default timeout for connecting to the GLEIF API.
+pub const CONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// The default timeout for reading a response of the GLEIF API.
+pub const READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// A blocking GLEIF API client which doesn't require an async runtime.
+///
+/// ```no_run
+/// use leim::gleif::blocking::Client;
+///
+/// let client = Client::new();
+/// let lei = "5493000IBP32UQZ0KL24".try_into().unwrap();
+/// if let Some(record) = client.lei_record(&lei).unwrap() {
+///     println!("{}", record.legal_name);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Client {
+    api: Api,
+    agent: ureq::Agent,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Client {
+    /// Constructs a client for the public GLEIF API.
+    pub fn new() -> Self {
+        Self::with_base_url(super::DEFAULT_BASE_URL)
+    }
+
+    /// Constructs a client for a GLEIF API compatible service
+    /// reachable at `base_url`. Connecting times out after
+    /// [`CONNECT_TIMEOUT`] and reading after [`READ_TIMEOUT`].
+    pub fn with_base_url(base_url: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout_connect(CONNECT_TIMEOUT)
+            .timeout_read(READ_TIMEOUT)
+            .build();
+        Self::with_agent(base_url, agent)
+    }
+
+    /// Constructs a client for a GLEIF API compatible service
+    /// reachable at `base_url`, which performs its requests with
+    /// `agent`, e.g. to configure timeouts or a proxy.
+    pub fn with_agent(base_url: &str, agent: ureq::Agent) -> Self {
+        Self {
+            api: Api::new(base_url),
+            agent,
+        }
+    }
+
+    /// Fetches the record of a single LEI. Returns `None` if the LEI
+    /// is not known to GLEIF.
+    pub fn lei_record(&self, lei: &LEI) -> Result<Option<LeiRecord>> {
+        let (status, body) = self.get(&self.api.lei_record_url(lei))?;
+        Api::decode_lei_record(status, &body)
+    }
+
+    /// Fetches the records of several LEIs at once. LEIs unknown to
+    /// GLEIF are missing from the result.
+    pub fn lei_records(&self, leis: &[LEI]) -> Result<Vec<LeiRecord>> {
+        let mut records = Vec::with_capacity(leis.len());
+        for chunk in leis.chunks(super::MAX_PAGE_SIZE) {
+            let (status, body) = self.get(&self.api.lei_records_url(chunk))?;
+            records.extend(Api::decode_lei_records(status, &body)?);
+        }
+        Ok(records)
+    }
+
+    fn get(&self, url: &str) -> Result<(u16, Vec<u8>)> {
+        let response = match self
+            .agent
+            .get(url)
+            .set("Accept", "application/vnd.api+json")
+            .call()
+        {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(Error::Transport(err.into())),
+        };
+        let status = response.status();
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(|err| Error::Transport(err.into()))?;
+        Ok((status, body))
+    }
+}
+
+impl crate::resolver::LeiResolver for Client {
+    type Error = Error;
+    fn resolve(&self, lei: &LEI) -> Result<Option<LeiRecord>> {
+        self.lei_record(lei)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Serves `responses` in order, one per connection, and returns the
+    /// base URL of the stub server together with a handle yielding the
+    /// received request lines.
+    fn stub_server(
+        responses: Vec<(u16, String)>,
+    ) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/api/v1", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                requests.push(request_line.trim_end().to_string());
+                // Drain the request headers.
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_lei_record() {
+        let body = crate::gleif::tests::lei_record_document();
+        let (base_url, handle) = stub_server(vec![(200, body), (404, String::new())]);
+        let client = Client::with_base_url(&base_url);
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+
+        let record = client.lei_record(&lei).unwrap().unwrap();
+        assert_eq!(record.lei, lei);
+        assert_eq!(record.legal_name, "Example AG");
+        assert!(client.lei_record(&lei).unwrap().is_none());
+
+        assert_eq!(
+            handle.join().unwrap(),
+            vec![
+                "GET /api/v1/lei-records/5493000IBP32UQZ0KL24 HTTP/1.1",
+                "GET /api/v1/lei-records/5493000IBP32UQZ0KL24 HTTP/1.1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lei_records() {
+        let body = crate::gleif::tests::lei_records_document();
+        let (base_url, handle) = stub_server(vec![(200, body)]);
+        let client = Client::with_base_url(&base_url);
+        let leis = [
+            LEI::try_from("5493000IBP32UQZ0KL24").unwrap(),
+            LEI::try_from("2594007XIACKNMUAW223").unwrap(),
+        ];
+
+        let records = client.lei_records(&leis).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].lei, leis[0]);
+        assert_eq!(
+            handle.join().unwrap(),
+            vec!["GET /api/v1/lei-records?filter[lei]=5493000IBP32UQZ0KL24,2594007XIACKNMUAW223&page[size]=200 HTTP/1.1"]
+        );
+    }
+
+    #[test]
+    fn test_server_error() {
+        let (base_url, handle) = stub_server(vec![(503, String::new())]);
+        let client = Client::with_base_url(&base_url);
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert!(matches!(client.lei_record(&lei), Err(Error::Status(503))));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_transport_error() {
+        // Bind and immediately drop a listener to obtain a closed port.
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let client = Client::with_base_url(&format!("http://{addr}"));
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert!(matches!(client.lei_record(&lei), Err(Error::Transport(_))));
+    }
+
+    #[test]
+    fn test_read_timeout() {
+        // Accept the connection but never respond.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let agent = ureq::AgentBuilder::new()
+            .timeout_read(std::time::Duration::from_millis(50))
+            .build();
+        let client = Client::with_agent(&base_url, agent);
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert!(matches!(client.lei_record(&lei), Err(Error::Transport(_))));
+        drop(listener);
+    }
+}
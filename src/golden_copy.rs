@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use crate::record::LeiRecord;
+use crate::resolver::LeiResolver;
+use crate::LEI;
+
+/// The errors emitted when loading a golden copy file.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The file is not valid CSV.
+    #[error("invalid CSV: {0}")]
+    Csv(#[from] csv::Error),
+    /// A column required to construct a [`LeiRecord`] is missing.
+    #[error("missing column: {0}")]
+    MissingColumn(&'static str),
+    /// A row contains an invalid LEI or status.
+    #[error("invalid value in line {line}: {source}")]
+    InvalidValue { line: u64, source: crate::Error },
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+const LEI_COLUMN: &str = "LEI";
+const LEGAL_NAME_COLUMN: &str = "Entity.LegalName";
+const JURISDICTION_COLUMN: &str = "Entity.LegalJurisdiction";
+const ENTITY_STATUS_COLUMN: &str = "Entity.EntityStatus";
+const SUCCESSOR_LEI_COLUMN: &str = "Entity.SuccessorEntity.SuccessorLEI";
+const REGISTRATION_STATUS_COLUMN: &str = "Registration.RegistrationStatus";
+const MANAGING_LOU_COLUMN: &str = "Registration.ManagingLOU";
+
+/// An in-memory index of a GLEIF golden copy, allowing offline LEI
+/// lookups.
+///
+/// The index is loaded from the CSV flavour of the LEI-CDF golden copy
+/// (or a delta file), see
+/// <https://www.gleif.org/en/lei-data/gleif-golden-copy/download-the-golden-copy>
+#[derive(Clone, Debug, Default)]
+pub struct GoldenCopyIndex {
+    records: HashMap<LEI, LeiRecord>,
+}
+
+impl GoldenCopyIndex {
+    /// Loads the records of a golden copy CSV file.
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self> {
+        let mut index = Self::default();
+        index.extend_from_reader(reader)?;
+        Ok(index)
+    }
+
+    /// Adds the records of a golden copy CSV file to the index,
+    /// replacing existing records of the same LEIs. This allows
+    /// applying delta files.
+    pub fn extend_from_reader<R: std::io::Read>(&mut self, reader: R) -> Result<()> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
+        let column = |name: &'static str| {
+            headers
+                .iter()
+                .position(|header| header == name)
+                .ok_or(Error::MissingColumn(name))
+        };
+        let lei = column(LEI_COLUMN)?;
+        let legal_name = column(LEGAL_NAME_COLUMN)?;
+        let jurisdiction = column(JURISDICTION_COLUMN)?;
+        let entity_status = column(ENTITY_STATUS_COLUMN)?;
+        let successor_lei = column(SUCCESSOR_LEI_COLUMN)?;
+        let registration_status = column(REGISTRATION_STATUS_COLUMN)?;
+        let managing_lou = column(MANAGING_LOU_COLUMN)?;
+
+        for row in reader.records() {
+            let row = row?;
+            let line = row.position().map_or(0, csv::Position::line);
+            let field = |i: usize| row.get(i).unwrap_or_default();
+            let optional = |i: usize| Some(field(i)).filter(|f| !f.is_empty());
+            let invalid = |source| Error::InvalidValue { line, source };
+            let parse_lei = |s: &str| LEI::try_from(s).map_err(invalid);
+            let record = LeiRecord {
+                lei: parse_lei(field(lei))?,
+                legal_name: field(legal_name).into(),
+                jurisdiction: optional(jurisdiction).map(Into::into),
+                entity_status: field(entity_status).try_into().map_err(invalid)?,
+                registration_status: field(registration_status).try_into().map_err(invalid)?,
+                managing_lou: parse_lei(field(managing_lou))?,
+                successor_lei: optional(successor_lei).map(parse_lei).transpose()?,
+            };
+            self.records.insert(record.lei.clone(), record);
+        }
+        Ok(())
+    }
+
+    /// Returns the record of `lei`, if it is part of the golden copy.
+    pub fn get(&self, lei: &LEI) -> Option<&LeiRecord> {
+        self.records.get(lei)
+    }
+
+    /// Returns the number of records in the index.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if the index holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+impl LeiResolver for GoldenCopyIndex {
+    type Error = std::convert::Infallible;
+    fn resolve(&self, lei: &LEI) -> std::result::Result<Option<LeiRecord>, Self::Error> {
+        Ok(self.get(lei).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::RegistrationStatus;
+
+    const GOLDEN_COPY: &str = "\
+\"LEI\",\"Entity.LegalName\",\"Entity.LegalJurisdiction\",\"Entity.EntityStatus\",\"Entity.SuccessorEntity.SuccessorLEI\",\"Registration.RegistrationStatus\",\"Registration.ManagingLOU\"
+\"5493000IBP32UQZ0KL24\",\"Example AG\",\"CH\",\"ACTIVE\",\"\",\"ISSUED\",\"5299000J2N45DDNE4Y28\"
+\"2594007XIACKNMUAW223\",\"Example, Old AG\",\"\",\"INACTIVE\",\"5493000IBP32UQZ0KL24\",\"RETIRED\",\"5299000J2N45DDNE4Y28\"
+";
+
+    #[test]
+    fn test_load() {
+        let index = GoldenCopyIndex::from_reader(GOLDEN_COPY.as_bytes()).unwrap();
+        assert_eq!(index.len(), 2);
+
+        let lei = LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        let record = index.resolve(&lei).unwrap().unwrap();
+        assert_eq!(record.legal_name, "Example, Old AG");
+        assert_eq!(record.jurisdiction, None);
+        assert_eq!(record.registration_status, RegistrationStatus::Retired);
+        assert_eq!(
+            record.successor_lei,
+            Some(LEI::try_from("5493000IBP32UQZ0KL24").unwrap())
+        );
+
+        let unknown = LEI::try_from("213800WSGIIZCXF1P572").unwrap();
+        assert_eq!(index.resolve(&unknown), Ok(None));
+    }
+
+    #[test]
+    fn test_delta() {
+        let mut index = GoldenCopyIndex::from_reader(GOLDEN_COPY.as_bytes()).unwrap();
+        let delta = GOLDEN_COPY.replace("\"ACTIVE\",\"\",\"ISSUED\"", "\"ACTIVE\",\"\",\"LAPSED\"");
+        index.extend_from_reader(delta.as_bytes()).unwrap();
+        assert_eq!(index.len(), 2);
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert_eq!(
+            index.get(&lei).unwrap().registration_status,
+            RegistrationStatus::Lapsed
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            GoldenCopyIndex::from_reader("\"LEI\"\n".as_bytes()),
+            Err(Error::MissingColumn(LEGAL_NAME_COLUMN))
+        ));
+        assert!(matches!(
+            GoldenCopyIndex::from_reader(
+                GOLDEN_COPY
+                    .replace("L24\",\"Example", "L25\",\"Example")
+                    .as_bytes()
+            ),
+            Err(Error::InvalidValue {
+                line: 2,
+                source: crate::Error::InvalidChecksum
+            })
+        ));
+        assert!(matches!(
+            GoldenCopyIndex::from_reader(GOLDEN_COPY.replace("RETIRED", "GONE").as_bytes()),
+            Err(Error::InvalidValue {
+                line: 3,
+                source: crate::Error::UnknownStatus(_)
+            })
+        ));
+    }
+}
@@ -0,0 +1,235 @@
+// Implements the scalars and defines the objects for the async-graphql
+// crate `$ag`. Each version needs its own object types, as the `Object`
+// macro keeps the resolvers as inherent methods. `$args` are passed on to
+// the attribute macros, as async-graphql 7 needs to be told the path of
+// the renamed crate. async-graphql 5 can't be told, see `v5`.
+macro_rules! impl_graphql {
+    ($ag:ident $(, $($args:tt)*)?) => {
+        /// A GraphQL object exposing the components of a LEI.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct LeiDetails(pub LEI);
+
+        impl From<LEI> for LeiDetails {
+            fn from(lei: LEI) -> Self {
+                Self(lei)
+            }
+        }
+
+        /// A GraphQL object exposing the details of a registration authority.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct RegistrationAuthorityDetails(pub RegistrationAuthority);
+
+        impl From<RegistrationAuthority> for RegistrationAuthorityDetails {
+            fn from(ra: RegistrationAuthority) -> Self {
+                Self(ra)
+            }
+        }
+
+        /// A 20-character Legal Entity Identifier as defined in ISO 17442, e.g.
+        /// `5493000IBP32UQZ0KL24`.
+        #[$ag::Scalar(
+            name = "LEI",
+            specified_by_url = "https://www.iso.org/standard/78829.html"
+            $(, $($args)*)?
+        )]
+        impl $ag::ScalarType for LEI {
+            fn parse(value: $ag::Value) -> $ag::InputValueResult<Self> {
+                match &value {
+                    $ag::Value::String(s) => Ok(Self::try_from(s.as_str())?),
+                    _ => Err($ag::InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &$ag::Value) -> bool {
+                matches!(value, $ag::Value::String(_))
+            }
+
+            fn to_value(&self) -> $ag::Value {
+                $ag::Value::String(self.to_string())
+            }
+        }
+
+        /// The code of a registration authority from the GLEIF Registration
+        /// Authorities List, e.g. `RA000548`.
+        #[$ag::Scalar(name = "RegistrationAuthority" $(, $($args)*)?)]
+        impl $ag::ScalarType for RegistrationAuthority {
+            fn parse(value: $ag::Value) -> $ag::InputValueResult<Self> {
+                match &value {
+                    $ag::Value::String(s) => Ok(Self::try_from(s.as_str())?),
+                    _ => Err($ag::InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &$ag::Value) -> bool {
+                matches!(value, $ag::Value::String(_))
+            }
+
+            fn to_value(&self) -> $ag::Value {
+                $ag::Value::String(self.to_string())
+            }
+        }
+
+        /// The components of a LEI.
+        #[$ag::Object($($($args)*)?)]
+        impl LeiDetails {
+            /// The LEI itself.
+            async fn lei(&self) -> &LEI {
+                &self.0
+            }
+
+            /// The prefix identifying the LEI issuer (LOU).
+            async fn lou_prefix(&self) -> &str {
+                self.0.lou_prefix()
+            }
+
+            /// The entity-specific part.
+            async fn entity_part(&self) -> &str {
+                self.0.entity_part()
+            }
+
+            /// The two check digits.
+            async fn check_digits(&self) -> &str {
+                self.0.check_digits()
+            }
+        }
+
+        /// The details of a registration authority.
+        #[$ag::Object($($($args)*)?)]
+        impl RegistrationAuthorityDetails {
+            /// The code of the registration authority.
+            async fn code(&self) -> &RegistrationAuthority {
+                &self.0
+            }
+
+            /// The numeric part of the code.
+            async fn number(&self) -> i32 {
+                self.0.number()
+            }
+        }
+    };
+}
+
+/// The objects for async-graphql 5.
+#[cfg(feature = "async-graphql-5")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-graphql-5")))]
+pub mod v5 {
+    use crate::registration_authority::RegistrationAuthority;
+    use crate::LEI;
+    // The async-graphql 5 macros refer to the crate by the name of the
+    // last async-graphql dependency in the manifest, which may be
+    // async-graphql 7.
+    #[allow(unused_imports)]
+    use async_graphql_5 as async_graphql_7;
+
+    impl_graphql!(async_graphql_5);
+}
+
+/// The objects for async-graphql 7.
+#[cfg(feature = "async-graphql-7")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-graphql-7")))]
+pub mod v7 {
+    use crate::registration_authority::RegistrationAuthority;
+    use crate::LEI;
+
+    impl_graphql!(async_graphql_7, crate = "async_graphql_7");
+}
+
+#[cfg(test)]
+mod tests {
+    // The tests for the async-graphql crate `$ag`, see `impl_graphql`.
+    macro_rules! tests {
+        ($version:ident, $ag:ident $(, $($args:tt)*)?) => {
+            use crate::graphql::$version::*;
+            use crate::registration_authority::RegistrationAuthority;
+            use crate::status::EntityStatus;
+            use crate::LEI;
+            use $ag::{EmptyMutation, EmptySubscription, Schema};
+
+            struct Query;
+
+            #[$ag::Object($($($args)*)?)]
+            impl Query {
+                async fn lei(&self, lei: LEI) -> LeiDetails {
+                    lei.into()
+                }
+
+                async fn ra(&self, ra: RegistrationAuthority) -> RegistrationAuthorityDetails {
+                    ra.into()
+                }
+
+                async fn status(&self, status: EntityStatus) -> EntityStatus {
+                    status
+                }
+            }
+
+            async fn execute(query: &str) -> $ag::Response {
+                Schema::new(Query, EmptyMutation, EmptySubscription)
+                    .execute(query)
+                    .await
+            }
+
+            #[tokio::test]
+            async fn test_objects() {
+                let response = execute(
+                    r#"{
+                        lei(lei: "5493000IBP32UQZ0KL24") { lei louPrefix entityPart checkDigits }
+                        ra(ra: "RA000548") { code number }
+                        status(status: INACTIVE)
+                    }"#,
+                )
+                .await;
+                assert!(response.errors.is_empty(), "{:?}", response.errors);
+                assert_eq!(
+                    response.data.into_json().unwrap(),
+                    serde_json::json!({
+                        "lei": {
+                            "lei": "5493000IBP32UQZ0KL24",
+                            "louPrefix": "5493",
+                            "entityPart": "000IBP32UQZ0KL",
+                            "checkDigits": "24"
+                        },
+                        "ra": { "code": "RA000548", "number": 548 },
+                        "status": "INACTIVE"
+                    })
+                );
+            }
+
+            #[tokio::test]
+            async fn test_parse_errors() {
+                let response = execute(r#"{ lei(lei: "5493000IBP32UQZ0KL25") { lei } }"#).await;
+                assert_eq!(
+                    response.errors[0].message,
+                    r#"Failed to parse "LEI": invalid checksum"#
+                );
+
+                let response = execute(r#"{ ra(ra: "RA100001") { code } }"#).await;
+                assert!(response.errors[0]
+                    .message
+                    .ends_with("unknown registration authority: RA100001"));
+            }
+
+            #[test]
+            fn test_sdl() {
+                let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+                assert!(sdl.contains("scalar LEI"));
+                assert!(sdl.contains("A 20-character Legal Entity Identifier"));
+                assert!(sdl.contains("scalar RegistrationAuthority"));
+                assert!(sdl.contains("enum EntityStatus"));
+                assert!(sdl.contains("The legal entity has ceased operation."));
+            }
+        };
+    }
+
+    #[cfg(feature = "async-graphql-5")]
+    mod v5 {
+        #[allow(unused_imports)]
+        use async_graphql_5 as async_graphql_7;
+
+        tests!(v5, async_graphql_5);
+    }
+
+    #[cfg(feature = "async-graphql-7")]
+    mod v7 {
+        tests!(v7, async_graphql_7, crate = "async_graphql_7");
+    }
+}
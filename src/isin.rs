@@ -0,0 +1,149 @@
+use crate::{Error, Result};
+
+/// A 12-character International Securities Identification Number as
+/// defined in ISO 6166.
+///
+/// An ISIN consists of a 2 letter country code, a 9 character national
+/// securities identifying number and a check digit computed with the
+/// Luhn algorithm over the digits of the first 11 characters, letters
+/// being expanded to the numbers 10 to 35.
+/// <https://www.isin.org/isin/>
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(transparent)]
+pub struct ISIN {
+    isin: String,
+}
+
+impl<'de> serde::Deserialize<'de> for ISIN {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> std::result::Result<Self, D::Error> {
+        let string: String = serde::Deserialize::deserialize(d)?;
+        string.as_str().try_into().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+sqlx_text!(ISIN);
+
+impl std::fmt::Display for ISIN {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.isin.fmt(f)
+    }
+}
+
+impl TryFrom<&str> for ISIN {
+    type Error = Error;
+    fn try_from(from: &str) -> Result<Self> {
+        if from.len() != 12 {
+            return Err(Error::InvalidIsinLength(from.len()));
+        }
+        for (pos, char) in from.chars().enumerate() {
+            let valid = match pos {
+                // The country code
+                0 | 1 => char.is_ascii_uppercase(),
+                // The check digit
+                11 => char.is_ascii_digit(),
+                _ => char.is_ascii_uppercase() || char.is_ascii_digit(),
+            };
+            if !valid {
+                return Err(Error::InvalidChar { pos, char });
+            }
+        }
+        // `u32::is_multiple_of` needs Rust 1.87.
+        #[allow(clippy::manual_is_multiple_of)]
+        if luhn_sum(from) % 10 != 0 {
+            return Err(Error::InvalidChecksum);
+        }
+        Ok(Self { isin: from.into() })
+    }
+}
+
+impl ISIN {
+    /// Returns the ISO 3166-1 alpha-2 country code of the issuer, or
+    /// `XS` for international securities.
+    pub fn country_code(&self) -> &str {
+        &self.isin[..2]
+    }
+
+    /// Returns the national securities identifying number.
+    pub fn nsin(&self) -> &str {
+        &self.isin[2..11]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u32 {
+        (self.isin.as_bytes()[11] - b'0').into()
+    }
+}
+
+// Computes the Luhn sum of the digits of `isin` with letters expanded to
+// two digits. Assumes `isin` consists of uppercase ASCII alphanumerics.
+fn luhn_sum(isin: &str) -> u32 {
+    let digits: String = isin
+        .chars()
+        .map(|c| c.to_digit(36).unwrap().to_string())
+        .collect();
+    digits
+        .bytes()
+        .rev()
+        .map(|b| u32::from(b - b'0'))
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            0 => digit,
+            _ if digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[test]
+    fn test_happy_parse() {
+        // from https://en.wikipedia.org/wiki/International_Securities_Identification_Number
+        let isin = ISIN::try_from("US0378331005").unwrap();
+        assert_eq!(isin.country_code(), "US");
+        assert_eq!(isin.nsin(), "037833100");
+        assert_eq!(isin.check_digit(), 5);
+        ISIN::try_from("AU0000XVGZA3").unwrap();
+        ISIN::try_from("GB0002634946").unwrap();
+        ISIN::try_from("DE000BAY0017").unwrap();
+        ISIN::try_from("CH0012221716").unwrap();
+    }
+
+    #[test]
+    fn test_malformed() {
+        for isin in ["", "US037833100", "US03783310055"] {
+            assert_eq!(
+                ISIN::try_from(isin).unwrap_err(),
+                Error::InvalidIsinLength(isin.len())
+            );
+        }
+        assert_eq!(
+            ISIN::try_from("US0378331006").unwrap_err(),
+            Error::InvalidChecksum
+        );
+        // Swapping adjacent digits is detected.
+        assert_eq!(
+            ISIN::try_from("US0378313005").unwrap_err(),
+            Error::InvalidChecksum
+        );
+        assert_eq!(
+            ISIN::try_from("1S0378331005").unwrap_err(),
+            Error::InvalidChar { pos: 0, char: '1' }
+        );
+        assert_eq!(
+            ISIN::try_from("US037833100A").unwrap_err(),
+            Error::InvalidChar { pos: 11, char: 'A' }
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let isin = ISIN::try_from("US0378331005").unwrap();
+        assert_tokens(&isin, &[Token::Str("US0378331005")]);
+        assert_de_tokens_error::<ISIN>(&[Token::Str("US0378331006")], "invalid checksum");
+    }
+}
@@ -0,0 +1,223 @@
+use crate::LEI;
+
+/// The errors emitted when scanning an ISO 20022 message.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The message is not well-formed XML.
+    #[error("invalid XML: {0}")]
+    Xml(#[from] roxmltree::Error),
+}
+
+/// The role of the party or agent identified by a LEI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartyRole {
+    /// `Dbtr`
+    Debtor,
+    /// `Cdtr`
+    Creditor,
+    /// `UltmtDbtr`
+    UltimateDebtor,
+    /// `UltmtCdtr`
+    UltimateCreditor,
+    /// `DbtrAgt`
+    DebtorAgent,
+    /// `CdtrAgt`
+    CreditorAgent,
+    /// `InstgAgt`
+    InstructingAgent,
+    /// `InstdAgt`
+    InstructedAgent,
+    /// `IntrmyAgt1`, `IntrmyAgt2` or `IntrmyAgt3`
+    IntermediaryAgent,
+    /// `Ownr`, the owner of an account in a statement.
+    AccountOwner,
+    /// `Svcr`, the servicer of an account in a statement.
+    AccountServicer,
+}
+
+impl PartyRole {
+    fn from_element(name: &str) -> Option<Self> {
+        use PartyRole::*;
+        Some(match name {
+            "Dbtr" => Debtor,
+            "Cdtr" => Creditor,
+            "UltmtDbtr" => UltimateDebtor,
+            "UltmtCdtr" => UltimateCreditor,
+            "DbtrAgt" => DebtorAgent,
+            "CdtrAgt" => CreditorAgent,
+            "InstgAgt" => InstructingAgent,
+            "InstdAgt" => InstructedAgent,
+            "IntrmyAgt1" | "IntrmyAgt2" | "IntrmyAgt3" => IntermediaryAgent,
+            "Ownr" => AccountOwner,
+            "Svcr" => AccountServicer,
+            _ => return None,
+        })
+    }
+
+    /// Returns whether the party is a financial institution acting as
+    /// an agent.
+    pub fn is_agent(self) -> bool {
+        use PartyRole::*;
+        matches!(
+            self,
+            DebtorAgent | CreditorAgent | InstructingAgent | InstructedAgent | IntermediaryAgent
+        )
+    }
+}
+
+/// A `<LEI>` element found in an ISO 20022 message.
+#[derive(Debug, PartialEq)]
+pub struct LeiField {
+    /// The location of the element, e.g.
+    /// `/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[2]/Dbtr/Id/OrgId/LEI`.
+    /// Indices are 1-based and only given for repeated elements.
+    pub path: String,
+    /// The role of the closest enclosing party or agent, if known.
+    pub role: Option<PartyRole>,
+    /// The text of the element, with surrounding whitespace removed.
+    pub value: String,
+    /// The result of validating `value`.
+    pub lei: Result<LEI, crate::Error>,
+}
+
+/// Extracts and validates all `<LEI>` elements of an ISO 20022 message,
+/// in document order.
+///
+/// The message type is not checked, so this works for pacs.008,
+/// pacs.009, camt.053 and any other message using the `LEI` element of
+/// `OrganisationIdentification` or `FinancialInstitutionIdentification`.
+///
+/// ```
+/// use leim::iso20022::{scan, PartyRole};
+///
+/// let xml = "<Document><Dbtr><Id><OrgId><LEI>5493000IBP32UQZ0KL24</LEI></OrgId></Id></Dbtr></Document>";
+/// let fields = scan(xml).unwrap();
+/// assert_eq!(fields[0].path, "/Document/Dbtr/Id/OrgId/LEI");
+/// assert_eq!(fields[0].role, Some(PartyRole::Debtor));
+/// assert!(fields[0].lei.is_ok());
+/// ```
+pub fn scan(xml: &str) -> Result<Vec<LeiField>, Error> {
+    let document = roxmltree::Document::parse(xml)?;
+    Ok(document
+        .descendants()
+        .filter(|node| node.is_element() && node.tag_name().name() == "LEI")
+        .map(|node| {
+            let value = node.text().unwrap_or_default().trim().to_string();
+            LeiField {
+                path: path(node),
+                role: node
+                    .ancestors()
+                    .find_map(|ancestor| PartyRole::from_element(ancestor.tag_name().name())),
+                lei: value.as_str().try_into(),
+                value,
+            }
+        })
+        .collect())
+}
+
+fn path(node: roxmltree::Node) -> String {
+    let mut segments: Vec<String> = node
+        .ancestors()
+        .filter(|node| node.is_element())
+        .map(|node| {
+            let name = node.tag_name().name();
+            let same_name = |sibling: &roxmltree::Node| {
+                sibling.is_element() && sibling.tag_name().name() == name
+            };
+            // Both sibling iterators start with `node` itself.
+            let index = node.prev_siblings().skip(1).filter(same_name).count();
+            if index > 0 || node.next_siblings().skip(1).any(|s| same_name(&s)) {
+                format!("{name}[{}]", index + 1)
+            } else {
+                name.to_string()
+            }
+        })
+        .collect();
+    segments.reverse();
+    format!("/{}", segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // An abbreviated pacs.008 message with two transactions.
+    const PACS_008: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08">
+  <FIToFICstmrCdtTrf>
+    <GrpHdr>
+      <MsgId>MSG-1</MsgId>
+      <InstgAgt><FinInstnId><LEI>5299000J2N45DDNE4Y28</LEI></FinInstnId></InstgAgt>
+    </GrpHdr>
+    <CdtTrfTxInf>
+      <Dbtr><Id><OrgId><LEI>5493000IBP32UQZ0KL24</LEI></OrgId></Id></Dbtr>
+      <CdtrAgt><FinInstnId><LEI> 2594007XIACKNMUAW223 </LEI></FinInstnId></CdtrAgt>
+    </CdtTrfTxInf>
+    <CdtTrfTxInf>
+      <Cdtr><Id><OrgId><LEI>5493000IBP32UQZ0KL25</LEI></OrgId></Id></Cdtr>
+    </CdtTrfTxInf>
+  </FIToFICstmrCdtTrf>
+</Document>"#;
+
+    #[test]
+    fn test_scan() {
+        let fields = scan(PACS_008).unwrap();
+        let summary: Vec<_> = fields
+            .iter()
+            .map(|field| (field.path.as_str(), field.role, field.lei.is_ok()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "/Document/FIToFICstmrCdtTrf/GrpHdr/InstgAgt/FinInstnId/LEI",
+                    Some(PartyRole::InstructingAgent),
+                    true
+                ),
+                (
+                    "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/Dbtr/Id/OrgId/LEI",
+                    Some(PartyRole::Debtor),
+                    true
+                ),
+                (
+                    "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/CdtrAgt/FinInstnId/LEI",
+                    Some(PartyRole::CreditorAgent),
+                    true
+                ),
+                (
+                    "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[2]/Cdtr/Id/OrgId/LEI",
+                    Some(PartyRole::Creditor),
+                    false
+                ),
+            ]
+        );
+        assert_eq!(fields[2].value, "2594007XIACKNMUAW223");
+        assert_eq!(fields[3].lei, Err(crate::Error::InvalidChecksum));
+        assert!(fields[2].role.unwrap().is_agent());
+        assert!(!fields[1].role.unwrap().is_agent());
+    }
+
+    #[test]
+    fn test_scan_camt_053() {
+        let xml = r#"<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.08">
+            <BkToCstmrStmt><Stmt><Acct>
+                <Ownr><Id><OrgId><LEI>5493000IBP32UQZ0KL24</LEI></OrgId></Id></Ownr>
+                <Svcr><FinInstnId><LEI>5299000J2N45DDNE4Y28</LEI></FinInstnId></Svcr>
+            </Acct></Stmt></BkToCstmrStmt>
+        </Document>"#;
+        let roles: Vec<_> = scan(xml).unwrap().into_iter().map(|f| f.role).collect();
+        assert_eq!(
+            roles,
+            [
+                Some(PartyRole::AccountOwner),
+                Some(PartyRole::AccountServicer)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_xml() {
+        assert!(matches!(scan("<Document>"), Err(Error::Xml(_))));
+        assert!(scan("<Document/>").unwrap().is_empty());
+    }
+}
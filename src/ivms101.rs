@@ -0,0 +1,273 @@
+use crate::registration_authority::RegistrationAuthority;
+use crate::LEI;
+
+/// The errors emitted when validating a national identification.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    /// The national identifier of type `LEIX` is not a valid LEI.
+    #[error("invalid LEI: {0}")]
+    InvalidLei(#[from] crate::Error),
+    /// A registration authority is given for a LEI.
+    #[error("registration authority must be absent for LEIs")]
+    UnexpectedRegistrationAuthority,
+    /// No registration authority is given for an identifier other than
+    /// a LEI.
+    #[error("registration authority required for identifier type {0}")]
+    MissingRegistrationAuthority(NationalIdentifierType),
+    /// A country of issue is given.
+    #[error("country of issue must be absent for identifier type {0}")]
+    UnexpectedCountryOfIssue(NationalIdentifierType),
+    /// The identifier type is not allowed for legal persons.
+    #[error("identifier type {0} not allowed for legal persons")]
+    InvalidIdentifierType(NationalIdentifierType),
+}
+
+/// The type of a national identifier as defined in IVMS101.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum NationalIdentifierType {
+    /// Alien registration number
+    #[serde(rename = "ARNU")]
+    AlienRegistrationNumber,
+    /// Passport number
+    #[serde(rename = "CCPT")]
+    PassportNumber,
+    /// Registration authority identifier
+    #[serde(rename = "RAID")]
+    RegistrationAuthorityIdentifier,
+    /// Driver license number
+    #[serde(rename = "DRLC")]
+    DriverLicenseNumber,
+    /// Foreign investment identity number
+    #[serde(rename = "FIIN")]
+    ForeignInvestmentIdentityNumber,
+    /// Tax identification number
+    #[serde(rename = "TXID")]
+    TaxIdentificationNumber,
+    /// Social security number
+    #[serde(rename = "SOCS")]
+    SocialSecurityNumber,
+    /// Identity card number
+    #[serde(rename = "IDCD")]
+    IdentityCardNumber,
+    /// Legal Entity Identifier
+    #[serde(rename = "LEIX")]
+    LegalEntityIdentifier,
+    /// Unspecified
+    #[serde(rename = "MISC")]
+    Unspecified,
+}
+
+impl NationalIdentifierType {
+    /// Returns the IVMS101 code of this type, e.g. `LEIX`.
+    pub fn code(self) -> &'static str {
+        use NationalIdentifierType::*;
+        match self {
+            AlienRegistrationNumber => "ARNU",
+            PassportNumber => "CCPT",
+            RegistrationAuthorityIdentifier => "RAID",
+            DriverLicenseNumber => "DRLC",
+            ForeignInvestmentIdentityNumber => "FIIN",
+            TaxIdentificationNumber => "TXID",
+            SocialSecurityNumber => "SOCS",
+            IdentityCardNumber => "IDCD",
+            LegalEntityIdentifier => "LEIX",
+            Unspecified => "MISC",
+        }
+    }
+}
+
+impl std::fmt::Display for NationalIdentifierType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.code().fmt(f)
+    }
+}
+
+/// The `NationalIdentification` of an IVMS101 legal person.
+///
+/// ```
+/// use leim::{ivms101::NationalIdentification, LEI};
+///
+/// let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+/// let identification = NationalIdentification::from(lei);
+/// assert!(identification.validate().is_ok());
+/// assert_eq!(
+///     serde_json::to_string(&identification).unwrap(),
+///     r#"{"nationalIdentifier":"5493000IBP32UQZ0KL24","nationalIdentifierType":"LEIX"}"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NationalIdentification {
+    /// The identifier, e.g. a LEI.
+    pub national_identifier: String,
+    /// The type of `national_identifier`.
+    pub national_identifier_type: NationalIdentifierType,
+    /// The ISO 3166-1 alpha-2 code of the country which issued the
+    /// identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_of_issue: Option<String>,
+    /// The registration authority which issued the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registration_authority: Option<RegistrationAuthority>,
+}
+
+impl From<LEI> for NationalIdentification {
+    fn from(lei: LEI) -> Self {
+        Self {
+            national_identifier: lei.to_string(),
+            national_identifier_type: NationalIdentifierType::LegalEntityIdentifier,
+            country_of_issue: None,
+            registration_authority: None,
+        }
+    }
+}
+
+impl NationalIdentification {
+    /// Returns the LEI if the identifier is of type `LEIX`.
+    pub fn lei(&self) -> Option<Result<LEI, crate::Error>> {
+        (self.national_identifier_type == NationalIdentifierType::LegalEntityIdentifier)
+            .then(|| LEI::try_from(self.national_identifier.as_str()))
+    }
+
+    /// Checks the IVMS101 constraints on the national identification of
+    /// legal persons:
+    ///
+    /// - The identifier type must be `RAID`, `MISC`, `LEIX` or `TXID`
+    ///   (C7).
+    /// - There must not be a country of issue (C9).
+    /// - An identifier of type `LEIX` must be a valid LEI and must not
+    ///   have a registration authority.
+    /// - Any other identifier must have a registration authority.
+    pub fn validate(&self) -> Result<(), Error> {
+        use NationalIdentifierType::*;
+        let identifier_type = self.national_identifier_type;
+        if !matches!(
+            identifier_type,
+            RegistrationAuthorityIdentifier
+                | Unspecified
+                | LegalEntityIdentifier
+                | TaxIdentificationNumber
+        ) {
+            return Err(Error::InvalidIdentifierType(identifier_type));
+        }
+        if self.country_of_issue.is_some() {
+            return Err(Error::UnexpectedCountryOfIssue(identifier_type));
+        }
+        match self.lei() {
+            Some(lei) => {
+                lei?;
+                if self.registration_authority.is_some() {
+                    return Err(Error::UnexpectedRegistrationAuthority);
+                }
+            }
+            None => {
+                if self.registration_authority.is_none() {
+                    return Err(Error::MissingRegistrationAuthority(identifier_type));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_lei() {
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        let identification = NationalIdentification::from(lei.clone());
+        assert_eq!(identification.lei(), Some(Ok(lei)));
+        assert_eq!(identification.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let identification: NationalIdentification = serde_json::from_str(
+            r#"{
+                "nationalIdentifier": "CHE-123.456.789",
+                "nationalIdentifierType": "RAID",
+                "registrationAuthority": "RA000548"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(identification.lei(), None);
+        assert_eq!(
+            identification.registration_authority,
+            Some("RA000548".try_into().unwrap())
+        );
+        assert_eq!(identification.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate() {
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        let mut identification = NationalIdentification::from(lei);
+        identification.registration_authority = Some("RA000548".try_into().unwrap());
+        assert_eq!(
+            identification.validate(),
+            Err(Error::UnexpectedRegistrationAuthority)
+        );
+
+        identification.registration_authority = None;
+        identification.national_identifier = "5493000IBP32UQZ0KL25".into();
+        assert_eq!(
+            identification.validate(),
+            Err(Error::InvalidLei(crate::Error::InvalidChecksum))
+        );
+
+        identification.national_identifier_type = NationalIdentifierType::TaxIdentificationNumber;
+        assert_eq!(
+            identification.validate(),
+            Err(Error::MissingRegistrationAuthority(
+                NationalIdentifierType::TaxIdentificationNumber
+            ))
+        );
+
+        identification.registration_authority = Some("RA000548".try_into().unwrap());
+        identification.country_of_issue = Some("CH".into());
+        assert_eq!(
+            identification.validate(),
+            Err(Error::UnexpectedCountryOfIssue(
+                NationalIdentifierType::TaxIdentificationNumber
+            ))
+        );
+    }
+
+    #[test]
+    fn test_identifier_types() {
+        let mut identification = NationalIdentification {
+            national_identifier: "CHE-123.456.789".into(),
+            national_identifier_type: NationalIdentifierType::Unspecified,
+            country_of_issue: None,
+            registration_authority: Some("RA000548".try_into().unwrap()),
+        };
+        assert_eq!(identification.validate(), Ok(()));
+
+        for identifier_type in [
+            NationalIdentifierType::PassportNumber,
+            NationalIdentifierType::DriverLicenseNumber,
+            NationalIdentifierType::SocialSecurityNumber,
+        ] {
+            identification.national_identifier_type = identifier_type;
+            assert_eq!(
+                identification.validate(),
+                Err(Error::InvalidIdentifierType(identifier_type))
+            );
+        }
+    }
+
+    #[test]
+    fn test_lei_country_of_issue() {
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        let mut identification = NationalIdentification::from(lei);
+        identification.country_of_issue = Some("CH".into());
+        assert_eq!(
+            identification.validate(),
+            Err(Error::UnexpectedCountryOfIssue(
+                NationalIdentifierType::LegalEntityIdentifier
+            ))
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use ::juniper::{graphql_scalar, ScalarValue};
+
+use crate::registration_authority::RegistrationAuthority;
+use crate::LEI;
+
+/// A 20-character Legal Entity Identifier as defined in ISO 17442, e.g.
+/// `5493000IBP32UQZ0KL24`.
+#[graphql_scalar]
+#[graphql(
+    name = "LEI",
+    specified_by_url = "https://www.iso.org/standard/78829.html",
+    with = lei,
+    to_output_with = ScalarValue::from_displayable,
+    parse_token(String)
+)]
+type Lei = LEI;
+
+mod lei {
+    use super::Lei;
+
+    pub(super) fn from_input(s: &str) -> Result<Lei, Box<str>> {
+        Lei::try_from(s).map_err(|err| format!(r#"Failed to parse "LEI": {err}"#).into())
+    }
+}
+
+/// The code of a registration authority from the GLEIF Registration
+/// Authorities List, e.g. `RA000548`.
+#[graphql_scalar]
+#[graphql(
+    name = "RegistrationAuthority",
+    with = registration_authority,
+    to_output_with = ScalarValue::from_displayable,
+    parse_token(String)
+)]
+type Ra = RegistrationAuthority;
+
+mod registration_authority {
+    use super::Ra;
+
+    pub(super) fn from_input(s: &str) -> Result<Ra, Box<str>> {
+        Ra::try_from(s)
+            .map_err(|err| format!(r#"Failed to parse "RegistrationAuthority": {err}"#).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::juniper::{
+        graphql_object, graphql_value, EmptyMutation, EmptySubscription, InputValue, RootNode,
+        Variables,
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn lei(lei: LEI) -> LEI {
+            lei
+        }
+
+        fn ra(ra: RegistrationAuthority) -> RegistrationAuthority {
+            ra
+        }
+    }
+
+    fn execute(query: &str, variables: &[(&str, &str)]) -> Result<::juniper::Value, String> {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let variables: Variables = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), InputValue::scalar(value.to_string())))
+            .collect();
+        match ::juniper::execute_sync(query, None, &schema, &variables, &()) {
+            Ok((value, errors)) if errors.is_empty() => Ok(value),
+            Ok((_, errors)) => Err(format!("{errors:?}")),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(
+            execute(
+                r#"{ lei(lei: "5493000IBP32UQZ0KL24") ra(ra: "RA000548") }"#,
+                &[]
+            ),
+            Ok(graphql_value!({ "lei": "5493000IBP32UQZ0KL24", "ra": "RA000548" }))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = execute(
+            r#"query($lei: LEI!) { lei(lei: $lei) }"#,
+            &[("lei", "5493000IBP32UQZ0KL25")],
+        )
+        .unwrap_err();
+        assert!(
+            err.contains(r#"Failed to parse "LEI": invalid checksum"#),
+            "{err}"
+        );
+
+        let err = execute(
+            r#"query($ra: RegistrationAuthority!) { ra(ra: $ra) }"#,
+            &[("ra", "RA100001")],
+        )
+        .unwrap_err();
+        assert!(
+            err.contains("unknown registration authority: RA100001"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_introspection() {
+        let value = execute(
+            r#"{ __type(name: "LEI") { kind description specifiedByUrl } }"#,
+            &[],
+        )
+        .unwrap();
+        let lei = value
+            .as_object_value()
+            .unwrap()
+            .get_field_value("__type")
+            .unwrap();
+        assert_eq!(
+            lei,
+            &graphql_value!({
+                "kind": "SCALAR",
+                "description": "A 20-character Legal Entity Identifier as defined in ISO 17442, e.g.\n`5493000IBP32UQZ0KL24`.",
+                "specifiedByUrl": "https://www.iso.org/standard/78829.html",
+            })
+        );
+    }
+}
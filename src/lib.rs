@@ -14,13 +14,107 @@
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[macro_use]
+mod macros;
+
+/// Business Identifier Codes (BICs) as defined in ISO 9362.
+pub mod bic;
+/// Bulk validation of LEIs in CSV files.
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+pub mod bulk;
+/// Clients for the GLEIF API.
+///
+/// See <https://www.gleif.org/en/lei-data/gleif-api>
+#[cfg(feature = "gleif")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gleif")))]
+pub mod gleif;
+/// Offline LEI lookups based on the GLEIF golden copy.
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+pub mod golden_copy;
+/// GraphQL scalars for LEIs and registration authorities, and objects
+/// exposing their details.
+///
+/// The scalars and objects are implemented for async-graphql 5 and 7,
+/// selected with the `async-graphql-5` (or `async-graphql`) and
+/// `async-graphql-7` features, which can be enabled side by side. The
+/// objects of each version live in the `v5` and `v7` modules.
+#[cfg(any(feature = "async-graphql-5", feature = "async-graphql-7"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "async-graphql-5", feature = "async-graphql-7")))
+)]
+pub mod graphql;
+/// International Securities Identification Numbers (ISINs) as defined
+/// in ISO 6166.
+pub mod isin;
+/// Extraction of LEIs from ISO 20022 messages.
+#[cfg(feature = "iso20022")]
+#[cfg_attr(docsrs, doc(cfg(feature = "iso20022")))]
+pub mod iso20022;
+/// Support for LEIs as national identifiers of legal persons in the
+/// interVASP Messaging Standard IVMS101.
+///
+/// See <https://intervasp.org>
+#[cfg(feature = "ivms101")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ivms101")))]
+pub mod ivms101;
+/// juniper GraphQL scalars for LEIs and registration authorities.
+#[cfg(feature = "juniper")]
+#[cfg_attr(docsrs, doc(cfg(feature = "juniper")))]
+pub mod juniper;
+/// Mappings between LEIs and other identifiers, as published by GLEIF.
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+pub mod mapping;
+/// Market Identifier Codes (MICs) as defined in ISO 10383.
+pub mod mic;
+/// Compact binary representation of LEIs.
+pub mod packed;
+/// A Postgres domain for LEIs, validating LEIs in the database, and its
+/// diesel mapping.
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+pub mod postgres;
+/// Protocol Buffers messages for LEIs, registration authorities and LEI
+/// records, with validated conversions to the types of this crate.
+///
+/// The messages are defined in `proto/leim/v1/lei.proto` and the code in
+/// `src/proto/leim.v1.rs` is generated from it with `prost-build`, which
+/// the `proto` test checks.
+#[cfg(feature = "prost")]
+#[cfg_attr(docsrs, doc(cfg(feature = "prost")))]
+pub mod proto;
+/// LEI records as published by GLEIF.
+pub mod record;
 /// Functionality related to registration authorities.
 pub mod registration_authority;
+/// Resolution of LEIs to their records, with optional caching.
+pub mod resolver;
+/// Entity and registration statuses of LEI records.
+pub mod status;
+/// Resolution of superseded LEIs to their current successors.
+pub mod successor;
+/// Finding LEIs in free text.
+pub mod text;
+/// Parsing of verifiable LEI (vLEI) credentials.
+///
+/// See <https://www.gleif.org/en/vlei/introducing-the-verifiable-lei-vlei>
+#[cfg(feature = "vlei")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vlei")))]
+pub mod vlei;
+/// LEIs embedded in X.509 certificates as specified in ISO 17442-2.
+#[cfg(feature = "x509")]
+#[cfg_attr(docsrs, doc(cfg(feature = "x509")))]
+pub mod x509;
 
 use rand::Rng;
 
-/// The errors emitted when parsing a LEI.
+/// The errors emitted when parsing a LEI or one of the other
+/// identifiers, statuses and registration authorities of this crate.
 #[derive(Debug, PartialEq, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     /// The LEI had an invalid length.
     #[error("invalid length: {0}, expected 20")]
@@ -34,6 +128,52 @@ pub enum Error {
     /// The registration authority was not known.
     #[error("unknown registration authority: {0}")]
     UnknownRegistrationAuthority(String),
+    /// The BIC had an invalid length.
+    #[error("invalid BIC length: {0}, expected 8 or 11")]
+    InvalidBicLength(usize),
+    /// The ISIN had an invalid length.
+    #[error("invalid ISIN length: {0}, expected 12")]
+    InvalidIsinLength(usize),
+    /// The MIC had an invalid length.
+    #[error("invalid MIC length: {0}, expected 4")]
+    InvalidMicLength(usize),
+    /// The identifier was malformed.
+    #[error("invalid identifier: {0}")]
+    InvalidIdentifier(String),
+    /// The entity or registration status was not known.
+    #[error("unknown status: {0}")]
+    UnknownStatus(String),
+    /// The LEI base, i.e. the LEI without check digits, had an invalid
+    /// length.
+    #[error("invalid LEI base length: {0}, expected 18")]
+    InvalidBaseLength(usize),
+    /// The packed LEI had an invalid length.
+    #[error("invalid packed LEI length: {0}, expected 13")]
+    InvalidPackedLength(usize),
+    /// The packed LEI exceeded the range of 20 base-36 digits.
+    #[error("invalid packed LEI value")]
+    InvalidPackedValue,
+}
+
+impl Error {
+    /// Returns a stable, machine-readable name of the error variant,
+    /// e.g. `invalid_checksum`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidLength(_) => "invalid_length",
+            Self::InvalidChecksum => "invalid_checksum",
+            Self::InvalidChar { .. } => "invalid_char",
+            Self::UnknownRegistrationAuthority(_) => "unknown_registration_authority",
+            Self::InvalidBicLength(_) => "invalid_bic_length",
+            Self::InvalidIsinLength(_) => "invalid_isin_length",
+            Self::InvalidMicLength(_) => "invalid_mic_length",
+            Self::InvalidIdentifier(_) => "invalid_identifier",
+            Self::UnknownStatus(_) => "unknown_status",
+            Self::InvalidBaseLength(_) => "invalid_base_length",
+            Self::InvalidPackedLength(_) => "invalid_packed_length",
+            Self::InvalidPackedValue => "invalid_packed_value",
+        }
+    }
 }
 
 type Result<T> = std::result::Result<T, Error>;
@@ -41,12 +181,16 @@ type Result<T> = std::result::Result<T, Error>;
 /// A 20-character Legal Entity Identifier. The checksum validation
 /// happens according to ISO7064, similarly to  IBAN numbers.
 /// <https://www.gleif.org/en/about-lei/iso-17442-the-lei-code-structure>
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize)]
 #[cfg_attr(
     feature = "diesel",
     derive(diesel::deserialize::FromSqlRow, diesel::expression::AsExpression)
 )]
 #[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(
+    feature = "postgres",
+    diesel(sql_type = crate::postgres::sql_types::Lei)
+)]
 #[serde(transparent)]
 pub struct LEI {
     lei: String,
@@ -59,9 +203,48 @@ impl<'de> serde::Deserialize<'de> for LEI {
     }
 }
 
-#[cfg(feature = "async-graphql")]
-#[cfg_attr(docsrs, doc(cfg(feature = "async-graphql")))]
-async_graphql::scalar!(LEI);
+#[cfg(feature = "sqlx")]
+sqlx_text!(LEI);
+
+#[cfg(feature = "schemars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+impl schemars::JsonSchema for LEI {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "LEI".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A 20-character Legal Entity Identifier as defined in ISO 17442.",
+            "minLength": 20,
+            "maxLength": 20,
+            "pattern": Self::PATTERN,
+            "examples": ["5493000IBP32UQZ0KL24"],
+        })
+    }
+}
+
+#[cfg(feature = "utoipa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utoipa")))]
+impl utoipa::PartialSchema for LEI {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        utoipa::openapi::ObjectBuilder::new()
+            .schema_type(utoipa::openapi::Type::String)
+            .description(Some(
+                "A 20-character Legal Entity Identifier as defined in ISO 17442.",
+            ))
+            .min_length(Some(20))
+            .max_length(Some(20))
+            .pattern(Some(Self::PATTERN))
+            .examples(["5493000IBP32UQZ0KL24"])
+            .into()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utoipa")))]
+impl utoipa::ToSchema for LEI {}
 
 impl std::fmt::Display for LEI {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -75,6 +258,7 @@ impl TryFrom<&str> for LEI {
         if from.len() != 20 {
             return Err(Error::InvalidLength(from.len()));
         }
+        validate_chars(from)?;
         if !validate_checksum(from) {
             return Err(Error::InvalidChecksum);
         }
@@ -112,6 +296,10 @@ where
 }
 
 impl LEI {
+    /// A regular expression matching the format of LEIs. It does not
+    /// check the check digits.
+    pub const PATTERN: &'static str = "^[0-9A-Z]{18}[0-9]{2}$";
+
     /// Constructs a random LEI with a valid checksum (only for
     /// testing purposes).
     pub fn random() -> Self {
@@ -126,9 +314,112 @@ impl LEI {
             .map(char::from)
             .collect::<String>()
             .to_uppercase();
+        Self::from_base(&format!("{prefix}00{infix}")).unwrap()
+    }
+
+    /// Constructs a LEI from its 18-character base by appending the
+    /// check digits.
+    pub fn from_base(base: &str) -> Result<Self> {
+        if base.len() != 18 {
+            return Err(Error::InvalidBaseLength(base.len()));
+        }
+        validate_chars(base)?;
         // Use placeholder 0s to compute needed checksum
-        let checksum = 98 - mod_97(&format!("{prefix}00{infix}00")).unwrap();
-        Self::try_from(format!("{prefix}00{infix}{checksum:02}").as_str()).unwrap()
+        let checksum = 98 - mod_97(&format!("{base}00"))?;
+        Self::try_from(format!("{base}{checksum:02}").as_str())
+    }
+
+    /// Returns the prefix identifying the LEI issuer (LOU), i.e. the
+    /// first 4 characters.
+    pub fn lou_prefix(&self) -> &str {
+        &self.lei[..4]
+    }
+
+    /// Returns the entity-specific part, i.e. characters 5 to 18.
+    pub fn entity_part(&self) -> &str {
+        &self.lei[4..18]
+    }
+
+    /// Returns the two check digits.
+    pub fn check_digits(&self) -> &str {
+        &self.lei[18..]
+    }
+
+    /// Returns the valid LEIs `candidate` could be a typo of, in
+    /// lexicographic order. A valid `candidate` is returned as is.
+    ///
+    /// Case, whitespace and dashes are ignored. Candidates are derived by
+    /// substituting a single character or swapping two adjacent
+    /// characters, or by inserting or deleting a single character if
+    /// `candidate` is one character too short or too long.
+    pub fn suggestions(candidate: &str) -> Vec<Self> {
+        const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let candidate: Vec<u8> = candidate
+            .bytes()
+            .filter(|b| !b.is_ascii_whitespace() && *b != b'-')
+            .map(|b| b.to_ascii_uppercase())
+            .collect();
+        // LEIs are ASCII, and no single-byte edit turns a non-ASCII
+        // candidate into one.
+        if !candidate.is_ascii() {
+            return Vec::new();
+        }
+        let mut variants = Vec::new();
+        match candidate.len() {
+            19 => {
+                for pos in 0..=19 {
+                    for c in ALPHABET {
+                        let mut variant = candidate.clone();
+                        variant.insert(pos, *c);
+                        variants.push(variant);
+                    }
+                }
+            }
+            20 => {
+                if let Ok(Ok(lei)) = std::str::from_utf8(&candidate).map(Self::try_from) {
+                    return vec![lei];
+                }
+                for pos in 0..20 {
+                    for c in ALPHABET {
+                        let mut variant = candidate.clone();
+                        variant[pos] = *c;
+                        variants.push(variant);
+                    }
+                    if pos < 19 {
+                        let mut variant = candidate.clone();
+                        variant.swap(pos, pos + 1);
+                        variants.push(variant);
+                    }
+                }
+            }
+            21 => {
+                for pos in 0..21 {
+                    let mut variant = candidate.clone();
+                    variant.remove(pos);
+                    variants.push(variant);
+                }
+            }
+            _ => {}
+        }
+        let leis: std::collections::BTreeSet<String> = variants
+            .into_iter()
+            .filter_map(|variant| String::from_utf8(variant).ok())
+            .filter(|variant| Self::try_from(variant.as_str()).is_ok())
+            .collect();
+        leis.into_iter().map(|lei| Self { lei }).collect()
+    }
+}
+
+// Checks that `lei`, a LEI or its base, consists of uppercase ASCII
+// letters and digits, with digits as check digits.
+pub(crate) fn validate_chars(lei: &str) -> Result<()> {
+    match lei
+        .chars()
+        .enumerate()
+        .find(|&(pos, char)| !(char.is_ascii_digit() || pos < 18 && char.is_ascii_uppercase()))
+    {
+        Some((pos, char)) => Err(Error::InvalidChar { pos, char }),
+        None => Ok(()),
     }
 }
 
@@ -198,10 +489,142 @@ mod tests {
             LEI::try_from("2594007XIACKNMUAW224").unwrap_err(),
             Error::InvalidChecksum
         );
+        assert_eq!(
+            LEI::try_from("5493000ibp32uqz0kl24").unwrap_err(),
+            Error::InvalidChar { pos: 7, char: 'i' }
+        );
+        assert_eq!(
+            LEI::try_from("5493000IBP32UQ#0KL24").unwrap_err(),
+            Error::InvalidChar { pos: 14, char: '#' }
+        );
     }
 
     #[test]
     fn test_random() {
         LEI::random();
     }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = regex::Regex::new(LEI::PATTERN).unwrap();
+        for lei in [
+            "2594007XIACKNMUAW223",
+            "5493000IBP32UQZ0KL24",
+            "5493000ibp32uqz0kl24",
+            "5493000IBP32UQ#0KL24",
+        ] {
+            assert_eq!(LEI::try_from(lei).is_ok(), pattern.is_match(lei), "{lei}");
+        }
+        for _ in 0..100 {
+            assert!(pattern.is_match(&LEI::random().to_string()));
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_schemars() {
+        use crate::record::LeiRecord;
+
+        let schema = schemars::schema_for!(LEI);
+        assert_eq!(schema.get("type"), Some(&"string".into()));
+        assert_eq!(schema.get("pattern"), Some(&LEI::PATTERN.into()));
+
+        let schema = schemars::schema_for!(LeiRecord).to_value();
+        assert_eq!(schema["properties"]["managingLou"]["$ref"], "#/$defs/LEI");
+        assert_eq!(schema["$defs"]["LEI"]["pattern"], LEI::PATTERN);
+        // The statuses are documented per variant, hence `oneOf`.
+        let statuses: Vec<_> = schema["$defs"]["EntityStatus"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|status| &status["const"])
+            .collect();
+        assert_eq!(statuses, ["ACTIVE", "INACTIVE", "NULL"]);
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn test_utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(LEI::name(), "LEI");
+        let schema = serde_json::to_value(LEI::schema()).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["pattern"], LEI::PATTERN);
+        assert_eq!(schema["examples"][0], "5493000IBP32UQZ0KL24");
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn test_sqlx() {
+        use crate::bic::BIC;
+        use crate::registration_authority::RegistrationAuthority;
+        use sqlx::Connection;
+
+        let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        let ra = RegistrationAuthority::try_from("RA000548").unwrap();
+        let bic = BIC::try_from("UBSWCHZH80A").unwrap();
+        let row: (LEI, RegistrationAuthority, BIC) = sqlx::query_as("SELECT ?, ?, ?")
+            .bind(&lei)
+            .bind(&ra)
+            .bind(&bic)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(row, (lei, ra, bic));
+
+        let err = sqlx::query_scalar::<_, LEI>("SELECT '5493000IBP32UQZ0KL25'")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap_err();
+        match err {
+            sqlx::Error::ColumnDecode { source, .. } => {
+                assert_eq!(source.downcast_ref(), Some(&Error::InvalidChecksum))
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[test]
+    fn test_from_base() {
+        let lei = LEI::from_base("5493000IBP32UQZ0KL").unwrap();
+        assert_eq!(lei.to_string(), "5493000IBP32UQZ0KL24");
+        assert_eq!(lei.lou_prefix(), "5493");
+        assert_eq!(lei.entity_part(), "000IBP32UQZ0KL");
+        assert_eq!(lei.check_digits(), "24");
+        assert_eq!(
+            LEI::from_base("5493000IBP32UQZ0K").unwrap_err(),
+            Error::InvalidBaseLength(17)
+        );
+        assert_eq!(
+            LEI::from_base("5493000IBP32UQZ0kL").unwrap_err(),
+            Error::InvalidChar { pos: 16, char: 'k' }
+        );
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert_eq!(
+            LEI::suggestions("5493000IBP32UQZ0KL24"),
+            std::slice::from_ref(&lei)
+        );
+        // Substitution, transposition, omission and duplication
+        for typo in [
+            "5493000IBP32UQZ0KL28",
+            "549300I0BP32UQZ0KL24",
+            "5493000IBP3UQZ0KL24",
+            "5493000IBP322UQZ0KL24",
+            "5493-000i bp32 uqz0 kl24",
+        ] {
+            assert!(LEI::suggestions(typo).contains(&lei), "{typo}");
+        }
+        assert!(LEI::suggestions("5493000IBP32").is_empty());
+        // Multi-byte characters
+        assert!(LEI::suggestions("5493000IBP32UQZ0Kéx").is_empty());
+        assert!(LEI::suggestions("5493000IBP32UQZ0KL2é").is_empty());
+    }
 }
@@ -0,0 +1,46 @@
+// Implements the sqlx `Type`, `Encode` and `Decode` traits for a type
+// stored as text, which is validated on decoding via `TryFrom<&str>`.
+#[cfg(feature = "sqlx")]
+macro_rules! sqlx_text {
+    ($type:ty) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+        impl<DB: sqlx::Database> sqlx::Type<DB> for $type
+        where
+            String: sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <String as sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <String as sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+        impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for $type
+        where
+            String: sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> std::result::Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                self.to_string().encode_by_ref(buf)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+        impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for $type
+        where
+            &'r str: sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as sqlx::Database>::ValueRef<'r>,
+            ) -> std::result::Result<Self, sqlx::error::BoxDynError> {
+                let string = <&str as sqlx::Decode<DB>>::decode(value)?;
+                Ok(std::convert::TryFrom::try_from(string)?)
+            }
+        }
+    };
+}
@@ -0,0 +1,199 @@
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use leim::registration_authority::RegistrationAuthority;
+use leim::LEI;
+use serde_json::json;
+
+/// Validate, inspect and generate Legal Entity Identifiers (LEIs).
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// Print JSON instead of plain text.
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Validate LEIs, one per line. Exits with 1 if any LEI is invalid.
+    Validate {
+        /// The files to read, standard input if none are given.
+        files: Vec<PathBuf>,
+    },
+    /// Validate the LEIs in a column of a CSV file and print the
+    /// annotated rows, or a report with `--json`. The summary is printed
+    /// to standard error. Exits with 1 if any LEI is invalid.
+    ValidateCsv {
+        /// The file to read, standard input if not given.
+        file: Option<PathBuf>,
+        /// The name of the column holding the LEIs.
+        #[arg(short, long, default_value = "LEI")]
+        column: String,
+    },
+    /// Show the components of a LEI.
+    Inspect { lei: String },
+    /// Complete an 18-character LEI base with its check digits.
+    CheckDigits { base: String },
+    /// Generate random LEIs for testing.
+    Random {
+        /// The number of LEIs to generate.
+        #[arg(short, long, default_value_t = 1)]
+        count: usize,
+    },
+    /// Check a code against the GLEIF Registration Authorities List and
+    /// show its number. Exits with 1 if the code is unknown.
+    Ra { code: String },
+    /// Suggest valid LEIs for a mistyped one. Exits with 1 if there are
+    /// none.
+    Suggest { lei: String },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+// Returns whether the command succeeded.
+fn run(cli: Cli) -> Result<bool, Box<dyn std::error::Error>> {
+    let json = cli.json;
+    match cli.command {
+        Command::Validate { files } => {
+            let mut inputs: Vec<Box<dyn Read>> = Vec::new();
+            for file in &files {
+                inputs.push(Box::new(std::fs::File::open(file)?));
+            }
+            if files.is_empty() {
+                inputs.push(Box::new(std::io::stdin()));
+            }
+            let mut results = Vec::new();
+            for input in inputs {
+                for line in BufReader::new(input).lines() {
+                    let line = line?;
+                    let line = line.trim();
+                    if !line.is_empty() {
+                        results.push((line.to_string(), LEI::try_from(line)));
+                    }
+                }
+            }
+            let valid = results.iter().all(|(_, result)| result.is_ok());
+            if json {
+                let results: Vec<_> = results
+                    .iter()
+                    .map(|(lei, result)| match result {
+                        Ok(_) => json!({ "lei": lei, "valid": true }),
+                        Err(err) => json!({ "lei": lei, "valid": false, "error": err.to_string() }),
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&results)?);
+            } else {
+                for (lei, result) in &results {
+                    match result {
+                        Ok(_) => println!("{lei}\tvalid"),
+                        Err(err) => println!("{lei}\tinvalid: {err}"),
+                    }
+                }
+            }
+            Ok(valid)
+        }
+        Command::ValidateCsv { file, column } => {
+            let report = match file {
+                Some(file) => leim::bulk::validate(std::fs::File::open(file)?, &column)?,
+                None => leim::bulk::validate(std::io::stdin(), &column)?,
+            };
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                report.write_csv(std::io::stdout())?;
+            }
+            let summary = &report.summary;
+            eprintln!(
+                "{} rows, {} valid, {} invalid",
+                summary.total, summary.valid, summary.invalid
+            );
+            for (kind, count) in &summary.errors {
+                eprintln!("  {kind}: {count}");
+            }
+            Ok(report.is_valid())
+        }
+        Command::Inspect { lei } => {
+            let lei = LEI::try_from(lei.as_str())?;
+            if json {
+                let output = json!({
+                    "lei": lei,
+                    "louPrefix": lei.lou_prefix(),
+                    "entityPart": lei.entity_part(),
+                    "checkDigits": lei.check_digits(),
+                });
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            } else {
+                println!("LEI:          {lei}");
+                println!("LOU prefix:   {}", lei.lou_prefix());
+                println!("Entity part:  {}", lei.entity_part());
+                println!("Check digits: {}", lei.check_digits());
+            }
+            Ok(true)
+        }
+        Command::CheckDigits { base } => {
+            let lei = LEI::from_base(&base)?;
+            if json {
+                println!(
+                    "{}",
+                    json!({ "lei": lei, "checkDigits": lei.check_digits() })
+                );
+            } else {
+                println!("{lei}");
+            }
+            Ok(true)
+        }
+        Command::Random { count } => {
+            let leis: Vec<_> = (0..count).map(|_| LEI::random()).collect();
+            if json {
+                println!("{}", serde_json::to_string_pretty(&leis)?);
+            } else {
+                for lei in leis {
+                    println!("{lei}");
+                }
+            }
+            Ok(true)
+        }
+        Command::Ra { code } => {
+            let ra = RegistrationAuthority::try_from(code.as_str());
+            if json {
+                let output = match &ra {
+                    Ok(ra) => json!({ "code": ra, "known": true, "number": ra.number() }),
+                    Err(_) => json!({ "code": code, "known": false }),
+                };
+                println!("{output}");
+            } else {
+                match &ra {
+                    Ok(ra) => println!("{ra}\tknown\tnumber {}", ra.number()),
+                    Err(err) => println!("{code}\t{err}"),
+                }
+            }
+            Ok(ra.is_ok())
+        }
+        Command::Suggest { lei } => {
+            let suggestions = LEI::suggestions(&lei);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&suggestions)?);
+            } else {
+                for lei in &suggestions {
+                    println!("{lei}");
+                }
+            }
+            Ok(!suggestions.is_empty())
+        }
+    }
+}
@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::bic::BIC;
+use crate::isin::ISIN;
+use crate::mic::MIC;
+use crate::LEI;
+
+/// The errors emitted when loading a mapping file.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The file is not valid CSV.
+    #[error("invalid CSV: {0}")]
+    Csv(#[from] csv::Error),
+    /// A required column is missing.
+    #[error("missing column: {0}")]
+    MissingColumn(String),
+    /// A row contains an invalid identifier.
+    #[error("invalid value in line {line}: {source}")]
+    InvalidValue { line: u64, source: crate::Error },
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// An identifier which GLEIF publishes mappings to LEIs for.
+pub trait Identifier: for<'a> TryFrom<&'a str, Error = crate::Error> + Clone + Eq + Hash {
+    /// The name of the column holding the identifier in the GLEIF
+    /// mapping files.
+    const COLUMN: &'static str;
+
+    /// Returns the canonical form of the identifier used as key for
+    /// lookups.
+    fn normalize(self) -> Self {
+        self
+    }
+}
+
+impl Identifier for BIC {
+    const COLUMN: &'static str = "BIC";
+
+    /// BICs are keyed by their 11 character form, so looking up a BIC
+    /// without branch code finds the primary office.
+    fn normalize(self) -> Self {
+        self.to_bic11()
+    }
+}
+
+impl Identifier for ISIN {
+    const COLUMN: &'static str = "ISIN";
+}
+
+impl Identifier for MIC {
+    const COLUMN: &'static str = "MIC";
+}
+
+/// The relationships between LEIs and another identifier, as published
+/// by GLEIF in its mapping files.
+///
+/// A LEI may be related to several identifiers, whereas every
+/// identifier is related to a single LEI.
+///
+/// See <https://www.gleif.org/en/lei-data/lei-mapping>
+#[derive(Clone, Debug)]
+pub struct IdentifierMapping<T> {
+    identifiers: HashMap<LEI, Vec<T>>,
+    leis: HashMap<T, LEI>,
+}
+
+/// The relationships between LEIs and BICs, as published by GLEIF and
+/// SWIFT.
+///
+/// See <https://www.gleif.org/en/lei-data/lei-mapping/download-bic-to-lei-relationship-files>
+pub type BicMapping = IdentifierMapping<BIC>;
+
+/// The relationships between LEIs and the ISINs of the securities
+/// issued by the respective entities, as published by GLEIF.
+///
+/// See <https://www.gleif.org/en/lei-data/lei-mapping/download-isin-to-lei-relationship-files>
+pub type IsinMapping = IdentifierMapping<ISIN>;
+
+/// The relationships between LEIs and the MICs of the market operators
+/// and segments run by the respective entities, as published by GLEIF.
+pub type MicMapping = IdentifierMapping<MIC>;
+
+/// The relationships between LEIs and OpenCorporates IDs.
+pub type OpenCorporatesMapping = IdentifierMapping<OpenCorporatesId>;
+
+/// The relationships between LEIs and S&P Capital IQ company IDs.
+pub type CapitalIqMapping = IdentifierMapping<CapitalIqId>;
+
+/// The relationships between LEIs and QCC codes.
+pub type QccMapping = IdentifierMapping<QccCode>;
+
+impl<T> Default for IdentifierMapping<T> {
+    fn default() -> Self {
+        Self {
+            identifiers: HashMap::new(),
+            leis: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Identifier> IdentifierMapping<T> {
+    /// Loads a mapping CSV file with the columns `LEI` and
+    /// [`Identifier::COLUMN`].
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self> {
+        Self::from_reader_with_column(reader, T::COLUMN)
+    }
+
+    /// Loads a mapping CSV file with the columns `LEI` and `column`.
+    pub fn from_reader_with_column<R: std::io::Read>(reader: R, column: &str) -> Result<Self> {
+        let mut mapping = Self::default();
+        for row in Rows::new(reader, ["LEI", column])? {
+            let (line, [lei, identifier]) = row?;
+            let lei = LEI::try_from(lei.as_str())
+                .map_err(|source| Error::InvalidValue { line, source })?;
+            let identifier = T::try_from(identifier.as_str())
+                .map_err(|source| Error::InvalidValue { line, source })?;
+            mapping.insert(lei, identifier);
+        }
+        Ok(mapping)
+    }
+
+    /// Adds the relationship between `lei` and `identifier`, replacing
+    /// an existing relationship of `identifier`.
+    pub fn insert(&mut self, lei: LEI, identifier: T) {
+        let identifier = identifier.normalize();
+        if let Some(previous) = self.leis.insert(identifier.clone(), lei.clone()) {
+            if let Some(identifiers) = self.identifiers.get_mut(&previous) {
+                identifiers.retain(|i| *i != identifier);
+            }
+        }
+        self.identifiers.entry(lei).or_default().push(identifier);
+    }
+
+    /// Returns the identifiers related to `lei`, in their normalised
+    /// form.
+    pub fn identifiers(&self, lei: &LEI) -> &[T] {
+        self.identifiers.get(lei).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the LEI related to `identifier`.
+    pub fn lei(&self, identifier: &T) -> Option<&LEI> {
+        self.leis.get(&identifier.clone().normalize())
+    }
+
+    /// Returns the number of relationships.
+    pub fn len(&self) -> usize {
+        self.leis.len()
+    }
+
+    /// Returns `true` if the mapping holds no relationships.
+    pub fn is_empty(&self) -> bool {
+        self.leis.is_empty()
+    }
+}
+
+impl BicMapping {
+    /// Returns the BICs of `lei`, in their 11 character form.
+    pub fn bics(&self, lei: &LEI) -> &[BIC] {
+        self.identifiers(lei)
+    }
+}
+
+impl IsinMapping {
+    /// Returns the ISINs of the securities issued by `lei`.
+    pub fn isins(&self, lei: &LEI) -> &[ISIN] {
+        self.identifiers(lei)
+    }
+
+    /// Returns the LEI of the issuer of `isin`.
+    pub fn issuer(&self, isin: &ISIN) -> Option<&LEI> {
+        self.lei(isin)
+    }
+}
+
+// Defines an identifier newtype around a non-empty string which is
+// validated by `$validate`, a `fn(&str) -> crate::Result<()>`.
+macro_rules! identifier {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $column:literal, $validate:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                d: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let string: String = serde::Deserialize::deserialize(d)?;
+                string.as_str().try_into().map_err(serde::de::Error::custom)
+            }
+        }
+
+        #[cfg(feature = "sqlx")]
+        sqlx_text!($name);
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = crate::Error;
+            fn try_from(from: &str) -> crate::Result<Self> {
+                if from.is_empty() {
+                    return Err(crate::Error::InvalidIdentifier(from.into()));
+                }
+                let validate: fn(&str) -> crate::Result<()> = $validate;
+                validate(from)?;
+                Ok(Self(from.into()))
+            }
+        }
+
+        impl Identifier for $name {
+            const COLUMN: &'static str = $column;
+        }
+    };
+}
+
+fn check_chars(s: &str, valid: impl Fn(usize, char) -> bool) -> crate::Result<()> {
+    match s.chars().enumerate().find(|&(pos, char)| !valid(pos, char)) {
+        Some((pos, char)) => Err(crate::Error::InvalidChar { pos, char }),
+        None => Ok(()),
+    }
+}
+
+identifier! {
+    /// An OpenCorporates company identifier, consisting of a lowercase
+    /// jurisdiction code and the company number, e.g. `gb/00102498`.
+    OpenCorporatesId,
+    "OpenCorporatesID",
+    |s| {
+        let slash = s
+            .find('/')
+            .filter(|&slash| slash > 0 && slash + 1 < s.len())
+            .ok_or_else(|| crate::Error::InvalidIdentifier(s.into()))?;
+        check_chars(s, |pos, c| match pos {
+            pos if pos < slash => c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_',
+            _ => c.is_ascii_graphic(),
+        })
+    }
+}
+
+identifier! {
+    /// A numeric S&P Capital IQ company identifier.
+    CapitalIqId,
+    "SPGlobalCompanyID",
+    |s| check_chars(s, |_, c| c.is_ascii_digit())
+}
+
+identifier! {
+    /// A QCC code, identifying a company registered in mainland China.
+    QccCode,
+    "QCC",
+    |s| check_chars(s, |_, c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+impl OpenCorporatesId {
+    /// Returns the jurisdiction code, e.g. `gb` or `us_de`.
+    pub fn jurisdiction(&self) -> &str {
+        self.0.split_once('/').unwrap().0
+    }
+
+    /// Returns the company number within the jurisdiction.
+    pub fn company_number(&self) -> &str {
+        self.0.split_once('/').unwrap().1
+    }
+}
+
+/// An iterator over the rows of a CSV file yielding the line number
+/// and the values of the requested columns.
+struct Rows<R, const N: usize> {
+    records: csv::StringRecordsIntoIter<R>,
+    columns: [usize; N],
+}
+
+impl<R: std::io::Read, const N: usize> Rows<R, N> {
+    fn new(reader: R, names: [&str; N]) -> Result<Self> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
+        let mut columns = [0; N];
+        for (column, name) in columns.iter_mut().zip(names) {
+            *column = headers
+                .iter()
+                .position(|header| header.eq_ignore_ascii_case(name))
+                .ok_or_else(|| Error::MissingColumn(name.into()))?;
+        }
+        Ok(Self {
+            records: reader.into_records(),
+            columns,
+        })
+    }
+}
+
+impl<R: std::io::Read, const N: usize> Iterator for Rows<R, N> {
+    type Item = Result<(u64, [String; N])>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(err) => return Some(Err(err.into())),
+        };
+        let line = record.position().map_or(0, csv::Position::line);
+        let values = self
+            .columns
+            .map(|column| record.get(column).unwrap_or_default().trim().to_string());
+        Some(Ok((line, values)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BIC_MAPPING: &str = "\
+LEI,BIC
+5493000IBP32UQZ0KL24,UBSWCHZH80A
+5493000IBP32UQZ0KL24,UBSWCHZHXXX
+2594007XIACKNMUAW223,DEUTDEFF
+";
+
+    #[test]
+    fn test_bic_mapping() {
+        let mapping = BicMapping::from_reader(BIC_MAPPING.as_bytes()).unwrap();
+        assert_eq!(mapping.len(), 3);
+
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert_eq!(
+            mapping.bics(&lei),
+            [
+                BIC::try_from("UBSWCHZH80A").unwrap(),
+                BIC::try_from("UBSWCHZHXXX").unwrap()
+            ]
+        );
+        for bic in ["UBSWCHZH80A", "UBSWCHZH", "UBSWCHZHXXX"] {
+            assert_eq!(mapping.lei(&bic.try_into().unwrap()), Some(&lei));
+        }
+        assert_eq!(mapping.lei(&"UBSWCHZH80B".try_into().unwrap()), None);
+
+        let lei = LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        assert_eq!(mapping.bics(&lei), [BIC::try_from("DEUTDEFFXXX").unwrap()]);
+        assert_eq!(mapping.lei(&"DEUTDEFF".try_into().unwrap()), Some(&lei));
+        assert!(mapping
+            .bics(&LEI::try_from("213800WSGIIZCXF1P572").unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_reassigned_bic() {
+        let mut mapping = BicMapping::default();
+        let bic = BIC::try_from("DEUTDEFF").unwrap();
+        let old = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        let new = LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        mapping.insert(old.clone(), bic.clone());
+        mapping.insert(new.clone(), bic.clone());
+        assert_eq!(mapping.lei(&bic), Some(&new));
+        assert!(mapping.bics(&old).is_empty());
+    }
+
+    const ISIN_MAPPING: &str = "\
+LEI,ISIN
+5493000IBP32UQZ0KL24,US0378331005
+5493000IBP32UQZ0KL24,AU0000XVGZA3
+2594007XIACKNMUAW223,GB0002634946
+";
+
+    #[test]
+    fn test_isin_mapping() {
+        let mapping = IsinMapping::from_reader(ISIN_MAPPING.as_bytes()).unwrap();
+        assert_eq!(mapping.len(), 3);
+
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert_eq!(
+            mapping.isins(&lei),
+            [
+                ISIN::try_from("US0378331005").unwrap(),
+                ISIN::try_from("AU0000XVGZA3").unwrap()
+            ]
+        );
+        assert_eq!(
+            mapping.issuer(&"AU0000XVGZA3".try_into().unwrap()),
+            Some(&lei)
+        );
+        assert_eq!(mapping.issuer(&"DE000BAY0017".try_into().unwrap()), None);
+        assert!(mapping
+            .isins(&LEI::try_from("213800WSGIIZCXF1P572").unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_mic_mapping() {
+        let csv = "MIC,Operating MIC,LEI\nXSWX,XSWX,5493000IBP32UQZ0KL24\nXQMH,XSWX,5493000IBP32UQZ0KL24\n";
+        let mapping = MicMapping::from_reader(csv.as_bytes()).unwrap();
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        assert_eq!(mapping.identifiers(&lei).len(), 2);
+        assert_eq!(mapping.lei(&"XQMH".try_into().unwrap()), Some(&lei));
+    }
+
+    #[test]
+    fn test_other_mappings() {
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+
+        let csv = "LEI,OpenCorporatesID\n5493000IBP32UQZ0KL24,gb/00102498\n";
+        let mapping = OpenCorporatesMapping::from_reader(csv.as_bytes()).unwrap();
+        let id = &mapping.identifiers(&lei)[0];
+        assert_eq!(id.jurisdiction(), "gb");
+        assert_eq!(id.company_number(), "00102498");
+
+        let csv = "LEI,SPGlobalCompanyID\n5493000IBP32UQZ0KL24,4004205\n";
+        let mapping = CapitalIqMapping::from_reader(csv.as_bytes()).unwrap();
+        assert_eq!(mapping.lei(&"4004205".try_into().unwrap()), Some(&lei));
+
+        let csv = "LEI,Code\n5493000IBP32UQZ0KL24,ABC123\n";
+        let mapping = QccMapping::from_reader_with_column(csv.as_bytes(), "Code").unwrap();
+        assert_eq!(mapping.lei(&"ABC123".try_into().unwrap()), Some(&lei));
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(
+            OpenCorporatesId::try_from("gb00102498"),
+            Err(crate::Error::InvalidIdentifier("gb00102498".into()))
+        );
+        assert_eq!(
+            OpenCorporatesId::try_from("GB/00102498"),
+            Err(crate::Error::InvalidChar { pos: 0, char: 'G' })
+        );
+        assert_eq!(
+            OpenCorporatesId::try_from("gb/"),
+            Err(crate::Error::InvalidIdentifier("gb/".into()))
+        );
+        assert_eq!(
+            CapitalIqId::try_from("IQ4004205"),
+            Err(crate::Error::InvalidChar { pos: 0, char: 'I' })
+        );
+        assert_eq!(
+            QccCode::try_from(""),
+            Err(crate::Error::InvalidIdentifier(String::new()))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            BicMapping::from_reader("LEI\n".as_bytes()),
+            Err(Error::MissingColumn(column)) if column == "BIC"
+        ));
+        assert!(matches!(
+            BicMapping::from_reader(BIC_MAPPING.replace("DEUTDEFF", "DEUTDEF").as_bytes()),
+            Err(Error::InvalidValue {
+                line: 4,
+                source: crate::Error::InvalidBicLength(7)
+            })
+        ));
+        assert!(matches!(
+            IsinMapping::from_reader(ISIN_MAPPING.replace("946", "947").as_bytes()),
+            Err(Error::InvalidValue {
+                line: 4,
+                source: crate::Error::InvalidChecksum
+            })
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{Error, Result};
+
+/// A 4-character Market Identifier Code as defined in ISO 10383,
+/// identifying a trading venue or its operator.
+/// <https://www.iso20022.org/market-identifier-codes>
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(transparent)]
+pub struct MIC {
+    mic: String,
+}
+
+impl<'de> serde::Deserialize<'de> for MIC {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> std::result::Result<Self, D::Error> {
+        let string: String = serde::Deserialize::deserialize(d)?;
+        string.as_str().try_into().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+sqlx_text!(MIC);
+
+impl std::fmt::Display for MIC {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.mic.fmt(f)
+    }
+}
+
+impl TryFrom<&str> for MIC {
+    type Error = Error;
+    fn try_from(from: &str) -> Result<Self> {
+        if from.len() != 4 {
+            return Err(Error::InvalidMicLength(from.len()));
+        }
+        if let Some((pos, char)) = from
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_uppercase() && !c.is_ascii_digit())
+        {
+            return Err(Error::InvalidChar { pos, char });
+        }
+        Ok(Self { mic: from.into() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(MIC::try_from("XSWX").unwrap().to_string(), "XSWX");
+        MIC::try_from("360T").unwrap();
+        assert_eq!(
+            MIC::try_from("XSWXX").unwrap_err(),
+            Error::InvalidMicLength(5)
+        );
+        assert_eq!(
+            MIC::try_from("XsWX").unwrap_err(),
+            Error::InvalidChar { pos: 1, char: 's' }
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{Error, Result, LEI};
+
+// 36^20, the number of 20-character strings over the LEI alphabet.
+const LIMIT: u128 = 36u128.pow(20);
+
+/// A LEI packed into 13 bytes, e.g. for compact storage in databases.
+///
+/// The LEI is interpreted as a 20-digit base-36 number and stored in
+/// big-endian order. Since digits precede letters in both the LEI
+/// alphabet and in ASCII, packed LEIs sort in the same order as the
+/// LEIs themselves, which keeps range scans on packed columns working.
+///
+/// With the `diesel` or `sqlx` feature, packed LEIs can be stored in
+/// `Binary` (e.g. Postgres `BYTEA`) columns.
+///
+/// ```
+/// use leim::{packed::PackedLEI, LEI};
+///
+/// let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+/// let packed = lei.to_packed();
+/// assert_eq!(packed.as_bytes().len(), 13);
+/// assert_eq!(LEI::from_packed(packed.as_bytes()), Ok(lei));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::deserialize::FromSqlRow, diesel::expression::AsExpression)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Binary))]
+pub struct PackedLEI([u8; 13]);
+
+impl PackedLEI {
+    /// Returns the packed bytes.
+    pub fn as_bytes(&self) -> &[u8; 13] {
+        &self.0
+    }
+}
+
+impl From<&LEI> for PackedLEI {
+    fn from(lei: &LEI) -> Self {
+        let value = lei.lei.bytes().fold(0u128, |acc, b| {
+            // Valid LEIs only contain digits and uppercase letters.
+            acc * 36 + u128::from((b as char).to_digit(36).unwrap())
+        });
+        let mut bytes = [0; 13];
+        bytes.copy_from_slice(&value.to_be_bytes()[3..]);
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for PackedLEI {
+    type Error = Error;
+    fn try_from(from: &[u8]) -> Result<Self> {
+        let bytes: [u8; 13] = from
+            .try_into()
+            .map_err(|_| Error::InvalidPackedLength(from.len()))?;
+        let packed = Self(bytes);
+        LEI::try_from(packed)?;
+        Ok(packed)
+    }
+}
+
+impl TryFrom<PackedLEI> for LEI {
+    type Error = Error;
+    fn try_from(from: PackedLEI) -> Result<Self> {
+        let mut value = from
+            .0
+            .iter()
+            .fold(0u128, |acc, b| acc << 8 | u128::from(*b));
+        if value >= LIMIT {
+            return Err(Error::InvalidPackedValue);
+        }
+        let mut lei = [0; 20];
+        for c in lei.iter_mut().rev() {
+            *c = char::from_digit((value % 36) as u32, 36)
+                .unwrap()
+                .to_ascii_uppercase() as u8;
+            value /= 36;
+        }
+        // The bytes are ASCII digits and uppercase letters.
+        LEI::try_from(std::str::from_utf8(&lei).unwrap())
+    }
+}
+
+impl LEI {
+    /// Packs the LEI into 13 bytes, see [`PackedLEI`].
+    pub fn to_packed(&self) -> PackedLEI {
+        self.into()
+    }
+
+    /// Unpacks a LEI packed with [`LEI::to_packed`].
+    pub fn from_packed(bytes: &[u8]) -> Result<Self> {
+        PackedLEI::try_from(bytes)?.try_into()
+    }
+}
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Binary, DB> for PackedLEI
+where
+    DB: diesel::backend::Backend,
+    Vec<u8>: diesel::deserialize::FromSql<diesel::sql_types::Binary, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(Self::try_from(Vec::<u8>::from_sql(bytes)?.as_slice())?)
+    }
+}
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<DB> diesel::serialize::ToSql<diesel::sql_types::Binary, DB> for PackedLEI
+where
+    DB: diesel::backend::Backend,
+    [u8]: diesel::serialize::ToSql<diesel::sql_types::Binary, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.0.as_slice().to_sql(out)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+impl<DB: sqlx::Database> sqlx::Type<DB> for PackedLEI
+where
+    Vec<u8>: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for PackedLEI
+where
+    Vec<u8>: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> std::result::Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        self.0.to_vec().encode_by_ref(buf)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for PackedLEI
+where
+    &'r [u8]: sqlx::Decode<'r, DB>,
+{
+    fn decode(
+        value: <DB as sqlx::Database>::ValueRef<'r>,
+    ) -> std::result::Result<Self, sqlx::error::BoxDynError> {
+        let bytes = <&[u8] as sqlx::Decode<DB>>::decode(value)?;
+        Ok(Self::try_from(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEIS: [&str; 5] = [
+        "2594007XIACKNMUAW223",
+        "54930084UKLVMY22DS16",
+        "213800WSGIIZCXF1P572",
+        "5493000IBP32UQZ0KL24",
+        "RILFO74KP1CM8P6PCT96",
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for lei in LEIS {
+            let lei = LEI::try_from(lei).unwrap();
+            assert_eq!(LEI::from_packed(lei.to_packed().as_bytes()), Ok(lei));
+        }
+        let lei = LEI::from_base("ZZZZZZZZZZZZZZZZZZ").unwrap();
+        assert_eq!(LEI::from_packed(lei.to_packed().as_bytes()), Ok(lei));
+        for _ in 0..100 {
+            let lei = LEI::random();
+            assert_eq!(LEI::from_packed(lei.to_packed().as_bytes()), Ok(lei));
+        }
+        // Lowercase LEIs, which would pack like uppercase ones, are
+        // rejected.
+        assert!(LEI::try_from("5493000ibp32uqz0kl24").is_err());
+    }
+
+    #[test]
+    fn test_order() {
+        let mut leis: Vec<_> = LEIS.map(|lei| LEI::try_from(lei).unwrap()).into();
+        leis.push(LEI::random());
+        leis.sort_by_key(LEI::to_string);
+        let mut packed: Vec<_> = leis.iter().map(LEI::to_packed).collect();
+        packed.sort();
+        let unpacked: Vec<LEI> = packed.into_iter().map(|p| p.try_into().unwrap()).collect();
+        assert_eq!(unpacked, leis);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            LEI::from_packed(&[0; 12]),
+            Err(Error::InvalidPackedLength(12))
+        );
+        assert_eq!(
+            LEI::from_packed(&[0xff; 13]),
+            Err(Error::InvalidPackedValue)
+        );
+        let mut packed = *LEI::try_from(LEIS[0]).unwrap().to_packed().as_bytes();
+        packed[12] ^= 1;
+        assert_eq!(LEI::from_packed(&packed), Err(Error::InvalidChecksum));
+    }
+
+    #[cfg(feature = "diesel")]
+    #[test]
+    fn test_diesel() {
+        use diesel::sql_types::Binary;
+        use diesel::{Connection, IntoSql, RunQueryDsl};
+
+        let mut conn = diesel::SqliteConnection::establish(":memory:").unwrap();
+        let packed = LEI::try_from(LEIS[0]).unwrap().to_packed();
+        let result: PackedLEI = diesel::select(packed.into_sql::<Binary>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(result, packed);
+        assert!(diesel::select(diesel::dsl::sql::<Binary>("x'00'"))
+            .get_result::<PackedLEI>(&mut conn)
+            .is_err());
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn test_sqlx() {
+        use sqlx::Connection;
+
+        let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let packed = LEI::try_from(LEIS[0]).unwrap().to_packed();
+        let (result, length): (PackedLEI, i32) = sqlx::query_as("SELECT ?1, length(?1)")
+            .bind(packed)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!((result, length), (packed, 13));
+    }
+}
@@ -0,0 +1,99 @@
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{Output, ToSql};
+use diesel::sql_types::Text;
+
+use crate::LEI;
+
+/// Creates the `lei` domain, which rejects values that are not
+/// uppercase LEIs with a valid checksum, and the `lei_mod_97` function
+/// it uses. Suitable as `up.sql` of a diesel migration.
+///
+/// ```sql
+#[doc = include_str!("postgres/up.sql")]
+/// ```
+pub const UP: &str = include_str!("postgres/up.sql");
+
+/// Drops the `lei` domain and the `lei_mod_97` function. Suitable as
+/// `down.sql` of a diesel migration.
+pub const DOWN: &str = include_str!("postgres/down.sql");
+
+/// Diesel SQL types for the objects created by [`UP`].
+pub mod sql_types {
+    /// The `lei` domain. [`LEI`](crate::LEI) can be stored in columns of
+    /// this type, e.g. declared in a diesel schema as
+    /// `lei -> leim::postgres::sql_types::Lei`.
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "lei"))]
+    pub struct Lei;
+}
+
+impl diesel::deserialize::FromSql<sql_types::Lei, Pg> for LEI {
+    fn from_sql(bytes: PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        <Self as diesel::deserialize::FromSql<Text, Pg>>::from_sql(bytes)
+    }
+}
+
+impl ToSql<sql_types::Lei, Pg> for LEI {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> diesel::serialize::Result {
+        <Self as ToSql<Text, Pg>>::to_sql(self, out)
+    }
+}
+
+#[cfg(all(test, feature = "postgres-tests"))]
+mod tests {
+    use super::*;
+    use diesel::{Connection, IntoSql, PgConnection, RunQueryDsl};
+
+    // Runs `test` in a transaction which is rolled back, on the database
+    // configured via `LEIM_TEST_POSTGRES_URL`.
+    fn with_connection(test: impl FnOnce(&mut PgConnection)) {
+        let url = std::env::var("LEIM_TEST_POSTGRES_URL")
+            .expect("LEIM_TEST_POSTGRES_URL must point to a Postgres database");
+        let mut conn = PgConnection::establish(&url).unwrap();
+        conn.test_transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::connection::SimpleConnection::batch_execute(conn, UP)?;
+            test(conn);
+            Ok(())
+        });
+    }
+
+    #[test]
+    #[ignore = "requires LEIM_TEST_POSTGRES_URL"]
+    fn test_domain() {
+        with_connection(|conn| {
+            let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+            let result: LEI = diesel::select(lei.clone().into_sql::<sql_types::Lei>())
+                .get_result(conn)
+                .unwrap();
+            assert_eq!(result, lei);
+
+            let mod_97: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                "lei_mod_97('5493000IBP32UQZ0KL24')",
+            ))
+            .get_result(conn)
+            .unwrap();
+            assert_eq!(mod_97, 1);
+
+            let mod_97: Option<i32> =
+                diesel::select(diesel::dsl::sql::<
+                    diesel::sql_types::Nullable<diesel::sql_types::Integer>,
+                >("lei_mod_97('5493000IBP32UQ#0KL24')"))
+                .get_result(conn)
+                .unwrap();
+            assert_eq!(mod_97, None);
+
+            // The domain rejects what `LEI` rejects, lowercase included.
+            for invalid in ["5493000IBP32UQZ0KL25", "5493000ibp32uqz0kl24"] {
+                assert!(LEI::try_from(invalid).is_err(), "{invalid}");
+                // Use a savepoint, as the error aborts the transaction.
+                let checked = conn.transaction(|conn| {
+                    diesel::select(diesel::dsl::sql::<sql_types::Lei>(&format!(
+                        "'{invalid}'::lei"
+                    )))
+                    .get_result::<LEI>(conn)
+                });
+                assert!(checked.is_err(), "{invalid}");
+            }
+        });
+    }
+}
@@ -0,0 +1,2 @@
+DROP DOMAIN lei;
+DROP FUNCTION lei_mod_97(text);
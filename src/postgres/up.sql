@@ -0,0 +1,18 @@
+-- Computes the ISO 7064 MOD 97-10 remainder of a LEI, 1 for valid LEIs,
+-- or NULL if the value contains characters other than 0-9 and A-Z.
+CREATE FUNCTION lei_mod_97(value text) RETURNS integer
+LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+    SELECT CASE WHEN value ~ '^[0-9A-Z]+$' THEN (
+        SELECT (
+            string_agg(
+                CASE WHEN c BETWEEN '0' AND '9' THEN c ELSE (ascii(c) - 55)::text END,
+                '' ORDER BY i
+            )::numeric % 97
+        )::integer
+        FROM unnest(string_to_array(value, NULL)) WITH ORDINALITY AS chars(c, i)
+    ) END
+$$;
+
+CREATE DOMAIN lei AS char(20)
+    CHECK (VALUE ~ '^[0-9A-Z]{18}[0-9]{2}$' AND lei_mod_97(VALUE) = 1);
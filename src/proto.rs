@@ -0,0 +1,228 @@
+use crate::record::LeiRecord;
+use crate::registration_authority::RegistrationAuthority;
+use crate::status::{EntityStatus, RegistrationStatus};
+use crate::LEI;
+
+/// The messages of the `leim.v1` package.
+pub mod v1 {
+    include!("proto/leim.v1.rs");
+}
+
+/// The errors emitted when converting messages to the types of this
+/// crate.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    /// A field holds an invalid value.
+    #[error("invalid {field}: {source}")]
+    Invalid {
+        field: &'static str,
+        source: crate::Error,
+    },
+    /// A required field is not set.
+    #[error("missing {0}")]
+    Missing(&'static str),
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<LEI> for v1::Lei {
+    fn from(lei: LEI) -> Self {
+        Self { value: lei.lei }
+    }
+}
+
+impl TryFrom<v1::Lei> for LEI {
+    type Error = crate::Error;
+    fn try_from(from: v1::Lei) -> crate::Result<Self> {
+        Self::try_from(from.value.as_str())
+    }
+}
+
+impl From<RegistrationAuthority> for v1::RegistrationAuthority {
+    fn from(ra: RegistrationAuthority) -> Self {
+        Self {
+            code: ra.as_str().into(),
+        }
+    }
+}
+
+impl TryFrom<v1::RegistrationAuthority> for RegistrationAuthority {
+    type Error = crate::Error;
+    fn try_from(from: v1::RegistrationAuthority) -> crate::Result<Self> {
+        Self::try_from(from.code.as_str())
+    }
+}
+
+// The protobuf enums have the variants of the status enums, named
+// alike, and an additional `Unspecified` default. The matches are
+// exhaustive so that a variant added on either side fails to compile.
+macro_rules! status_conversions {
+    ($status:ident { $($variant:ident),* $(,)? }) => {
+        impl From<$status> for v1::$status {
+            fn from(status: $status) -> Self {
+                match status {
+                    $($status::$variant => Self::$variant,)*
+                }
+            }
+        }
+
+        impl TryFrom<v1::$status> for $status {
+            type Error = crate::Error;
+            fn try_from(from: v1::$status) -> crate::Result<Self> {
+                match from {
+                    $(v1::$status::$variant => Ok(Self::$variant),)*
+                    v1::$status::Unspecified => Err(crate::Error::UnknownStatus("UNSPECIFIED".into())),
+                }
+            }
+        }
+    };
+}
+
+status_conversions!(EntityStatus {
+    Active,
+    Inactive,
+    Null
+});
+status_conversions!(RegistrationStatus {
+    PendingValidation,
+    Issued,
+    Duplicate,
+    Lapsed,
+    Merged,
+    Retired,
+    Annulled,
+    Cancelled,
+    Transferred,
+    PendingTransfer,
+    PendingArchival,
+});
+
+// Converts the value of the protobuf enum field `field` to a status.
+fn status<P, S>(field: &'static str, value: i32) -> Result<S>
+where
+    P: TryFrom<i32, Error = prost::UnknownEnumValue>,
+    S: TryFrom<P, Error = crate::Error>,
+{
+    P::try_from(value)
+        .map_err(|err| crate::Error::UnknownStatus(err.0.to_string()))
+        .and_then(S::try_from)
+        .map_err(|source| Error::Invalid { field, source })
+}
+
+impl From<LeiRecord> for v1::LeiRecord {
+    fn from(record: LeiRecord) -> Self {
+        Self {
+            lei: Some(record.lei.into()),
+            legal_name: record.legal_name,
+            jurisdiction: record.jurisdiction,
+            entity_status: v1::EntityStatus::from(record.entity_status).into(),
+            registration_status: v1::RegistrationStatus::from(record.registration_status).into(),
+            managing_lou: Some(record.managing_lou.into()),
+            successor_lei: record.successor_lei.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<v1::LeiRecord> for LeiRecord {
+    type Error = Error;
+    fn try_from(from: v1::LeiRecord) -> Result<Self> {
+        let lei = |field, lei: Option<v1::Lei>| {
+            LEI::try_from(lei.ok_or(Error::Missing(field))?)
+                .map_err(|source| Error::Invalid { field, source })
+        };
+        Ok(Self {
+            lei: lei("lei", from.lei)?,
+            legal_name: from.legal_name,
+            jurisdiction: from.jurisdiction,
+            entity_status: status::<v1::EntityStatus, _>("entity_status", from.entity_status)?,
+            registration_status: status::<v1::RegistrationStatus, _>(
+                "registration_status",
+                from.registration_status,
+            )?,
+            managing_lou: lei("managing_lou", from.managing_lou)?,
+            successor_lei: from
+                .successor_lei
+                .map(|successor| lei("successor_lei", Some(successor)))
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    fn record() -> LeiRecord {
+        LeiRecord {
+            registration_status: RegistrationStatus::PendingTransfer,
+            ..crate::resolver::tests::record("5493000IBP32UQZ0KL24")
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let message = v1::LeiRecord::from(record());
+        assert_eq!(
+            message.registration_status(),
+            v1::RegistrationStatus::PendingTransfer
+        );
+        let decoded = v1::LeiRecord::decode(message.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(LeiRecord::try_from(decoded), Ok(record()));
+
+        for &status in EntityStatus::ALL {
+            assert_eq!(
+                EntityStatus::try_from(v1::EntityStatus::from(status)),
+                Ok(status)
+            );
+        }
+        for &status in RegistrationStatus::ALL {
+            let message = v1::RegistrationStatus::from(status);
+            assert!(message.as_str_name().ends_with(status.as_str()));
+            assert_eq!(RegistrationStatus::try_from(message), Ok(status));
+        }
+
+        let ra = RegistrationAuthority::try_from("RA000548").unwrap();
+        assert_eq!(
+            RegistrationAuthority::try_from(v1::RegistrationAuthority::from(ra.clone())),
+            Ok(ra)
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut message = v1::LeiRecord::from(record());
+        message.successor_lei = Some(v1::Lei {
+            value: "5493000IBP32UQZ0KL25".into(),
+        });
+        assert_eq!(
+            LeiRecord::try_from(message.clone()),
+            Err(Error::Invalid {
+                field: "successor_lei",
+                source: crate::Error::InvalidChecksum,
+            })
+        );
+
+        message.managing_lou = None;
+        assert_eq!(
+            LeiRecord::try_from(message.clone()),
+            Err(Error::Missing("managing_lou"))
+        );
+
+        let mut message = v1::LeiRecord::from(record());
+        message.entity_status = v1::EntityStatus::Unspecified.into();
+        assert_eq!(
+            LeiRecord::try_from(message.clone()),
+            Err(Error::Invalid {
+                field: "entity_status",
+                source: crate::Error::UnknownStatus("UNSPECIFIED".into()),
+            })
+        );
+
+        message.entity_status = 42;
+        assert_eq!(
+            LeiRecord::try_from(message).unwrap_err().to_string(),
+            "invalid entity_status: unknown status: 42"
+        );
+    }
+}
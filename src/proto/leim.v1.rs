@@ -0,0 +1,149 @@
+// This file is @generated by prost-build.
+/// A 20-character Legal Entity Identifier as defined in ISO 17442, e.g.
+/// `5493000IBP32UQZ0KL24`.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Lei {
+    /// The LEI, including its check digits.
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+}
+/// A registration authority from the GLEIF Registration Authorities List.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RegistrationAuthority {
+    /// The code of the registration authority, e.g. `RA000548`.
+    #[prost(string, tag = "1")]
+    pub code: ::prost::alloc::string::String,
+}
+/// The subset of a LEI record (as defined by the GLEIF LEI-CDF format)
+/// that leim works with.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LeiRecord {
+    /// The LEI this record belongs to.
+    #[prost(message, optional, tag = "1")]
+    pub lei: ::core::option::Option<Lei>,
+    /// The legal name of the entity.
+    #[prost(string, tag = "2")]
+    pub legal_name: ::prost::alloc::string::String,
+    /// The jurisdiction of the entity, e.g. `CH` or `US-DE`.
+    #[prost(string, optional, tag = "3")]
+    pub jurisdiction: ::core::option::Option<::prost::alloc::string::String>,
+    /// The status of the legal entity.
+    #[prost(enumeration = "EntityStatus", tag = "4")]
+    pub entity_status: i32,
+    /// The status of the LEI registration.
+    #[prost(enumeration = "RegistrationStatus", tag = "5")]
+    pub registration_status: i32,
+    /// The LEI of the LEI issuer (LOU) managing this record.
+    #[prost(message, optional, tag = "6")]
+    pub managing_lou: ::core::option::Option<Lei>,
+    /// The LEI of the successor entity, if there is one.
+    #[prost(message, optional, tag = "7")]
+    pub successor_lei: ::core::option::Option<Lei>,
+}
+/// The status of a legal entity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EntityStatus {
+    Unspecified = 0,
+    /// The legal entity is operating.
+    Active = 1,
+    /// The legal entity has ceased operation.
+    Inactive = 2,
+    /// The status is not applicable, e.g. for registrations which have not
+    /// been validated yet.
+    Null = 3,
+}
+impl EntityStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ENTITY_STATUS_UNSPECIFIED",
+            Self::Active => "ENTITY_STATUS_ACTIVE",
+            Self::Inactive => "ENTITY_STATUS_INACTIVE",
+            Self::Null => "ENTITY_STATUS_NULL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ENTITY_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "ENTITY_STATUS_ACTIVE" => Some(Self::Active),
+            "ENTITY_STATUS_INACTIVE" => Some(Self::Inactive),
+            "ENTITY_STATUS_NULL" => Some(Self::Null),
+            _ => None,
+        }
+    }
+}
+/// The status of a LEI registration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RegistrationStatus {
+    Unspecified = 0,
+    /// The registration has been submitted and awaits validation.
+    PendingValidation = 1,
+    /// The LEI has been issued and is maintained by its LOU.
+    Issued = 2,
+    /// The registration duplicates another one and has been superseded by
+    /// it.
+    Duplicate = 3,
+    /// The registration has not been renewed in time.
+    Lapsed = 4,
+    /// The legal entity has merged into another legal entity.
+    Merged = 5,
+    /// The legal entity has ceased operation or the registration has been
+    /// retired on request.
+    Retired = 6,
+    /// The registration has been found to be erroneous or invalid.
+    Annulled = 7,
+    /// The registration has been abandoned before the LEI was issued.
+    Cancelled = 8,
+    /// The registration has been transferred to another LOU.
+    Transferred = 9,
+    /// The registration is about to be transferred from another LOU.
+    PendingTransfer = 10,
+    /// The registration is about to be transferred to another LOU.
+    PendingArchival = 11,
+}
+impl RegistrationStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "REGISTRATION_STATUS_UNSPECIFIED",
+            Self::PendingValidation => "REGISTRATION_STATUS_PENDING_VALIDATION",
+            Self::Issued => "REGISTRATION_STATUS_ISSUED",
+            Self::Duplicate => "REGISTRATION_STATUS_DUPLICATE",
+            Self::Lapsed => "REGISTRATION_STATUS_LAPSED",
+            Self::Merged => "REGISTRATION_STATUS_MERGED",
+            Self::Retired => "REGISTRATION_STATUS_RETIRED",
+            Self::Annulled => "REGISTRATION_STATUS_ANNULLED",
+            Self::Cancelled => "REGISTRATION_STATUS_CANCELLED",
+            Self::Transferred => "REGISTRATION_STATUS_TRANSFERRED",
+            Self::PendingTransfer => "REGISTRATION_STATUS_PENDING_TRANSFER",
+            Self::PendingArchival => "REGISTRATION_STATUS_PENDING_ARCHIVAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REGISTRATION_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "REGISTRATION_STATUS_PENDING_VALIDATION" => Some(Self::PendingValidation),
+            "REGISTRATION_STATUS_ISSUED" => Some(Self::Issued),
+            "REGISTRATION_STATUS_DUPLICATE" => Some(Self::Duplicate),
+            "REGISTRATION_STATUS_LAPSED" => Some(Self::Lapsed),
+            "REGISTRATION_STATUS_MERGED" => Some(Self::Merged),
+            "REGISTRATION_STATUS_RETIRED" => Some(Self::Retired),
+            "REGISTRATION_STATUS_ANNULLED" => Some(Self::Annulled),
+            "REGISTRATION_STATUS_CANCELLED" => Some(Self::Cancelled),
+            "REGISTRATION_STATUS_TRANSFERRED" => Some(Self::Transferred),
+            "REGISTRATION_STATUS_PENDING_TRANSFER" => Some(Self::PendingTransfer),
+            "REGISTRATION_STATUS_PENDING_ARCHIVAL" => Some(Self::PendingArchival),
+            _ => None,
+        }
+    }
+}
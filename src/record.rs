@@ -0,0 +1,35 @@
+use crate::status::{EntityStatus, RegistrationStatus};
+use crate::LEI;
+
+/// The subset of a LEI record (as defined by the GLEIF LEI-CDF format)
+/// that this crate works with.
+///
+/// See <https://www.gleif.org/en/about-lei/common-data-file-format/current-versions/level-1-data-lei-cdf-3-1-format>
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LeiRecord {
+    /// The LEI this record belongs to.
+    pub lei: LEI,
+    /// The legal name of the entity.
+    pub legal_name: String,
+    /// The jurisdiction of the entity, e.g. `CH` or `US-DE`.
+    pub jurisdiction: Option<String>,
+    /// The status of the legal entity.
+    pub entity_status: EntityStatus,
+    /// The status of the LEI registration.
+    pub registration_status: RegistrationStatus,
+    /// The LEI of the LEI issuer (LOU) managing this record.
+    pub managing_lou: LEI,
+    /// The LEI of the successor entity, if there is one.
+    pub successor_lei: Option<LEI>,
+}
+
+impl LeiRecord {
+    /// Returns `true` if the LEI may be used to identify the entity in
+    /// regulatory reporting, see
+    /// [`RegistrationStatus::is_usable_for_reporting`].
+    pub fn is_usable_for_reporting(&self) -> bool {
+        self.registration_status.is_usable_for_reporting()
+    }
+}
@@ -1,7 +1,17 @@
 /// A registration authority.
 ///
 /// See <https://www.gleif.org/en/about-lei/code-lists/gleif-registration-authorities-list>
+///
+/// With the `diesel` feature, registration authorities can be stored
+/// either as their code in `Text` columns or as the numeric part of
+/// their code in `Integer` columns.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::deserialize::FromSqlRow, diesel::expression::AsExpression)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Integer))]
 #[serde(try_from = "&str")]
 pub struct RegistrationAuthority {
     index: usize,
@@ -9,10 +19,144 @@ pub struct RegistrationAuthority {
 
 impl serde::Serialize for RegistrationAuthority {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        REGISTRATION_AUTHORITIES
-            .get(self.index)
-            .unwrap()
-            .serialize(serializer)
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl RegistrationAuthority {
+    /// Returns all known registration authorities, ordered by their code.
+    pub fn all() -> impl Iterator<Item = Self> {
+        // Some codes are listed more than once, only the first is used.
+        (0..REGISTRATION_AUTHORITIES.len())
+            .filter(|&index| {
+                index == 0 || REGISTRATION_AUTHORITIES[index - 1] != REGISTRATION_AUTHORITIES[index]
+            })
+            .map(|index| Self { index })
+    }
+
+    /// Returns the code of the registration authority, e.g. `RA000548`.
+    pub fn as_str(&self) -> &'static str {
+        REGISTRATION_AUTHORITIES[self.index]
+    }
+
+    /// Returns the numeric part of the code, e.g. 548 for `RA000548`.
+    pub fn number(&self) -> i32 {
+        RA_NUMBERS[self.index]
+    }
+
+    /// Returns the registration authority with the numeric part
+    /// `number`.
+    pub fn from_number(number: i32) -> Result<Self, crate::Error> {
+        let index = RA_NUMBERS.partition_point(|&n| n < number);
+        if RA_NUMBERS.get(index) == Some(&number) {
+            Ok(Self { index })
+        } else {
+            Err(crate::Error::UnknownRegistrationAuthority(format!(
+                "RA{number:06}"
+            )))
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for RegistrationAuthority
+where
+    DB: diesel::backend::Backend,
+    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(std::convert::TryFrom::try_from(
+            String::from_sql(bytes)?.as_str(),
+        )?)
+    }
+}
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for RegistrationAuthority
+where
+    DB: diesel::backend::Backend,
+    str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for RegistrationAuthority
+where
+    DB: diesel::backend::Backend,
+    i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(Self::from_number(i32::from_sql(bytes)?)?)
+    }
+}
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for RegistrationAuthority
+where
+    DB: diesel::backend::Backend,
+    i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        RA_NUMBERS[self.index].to_sql(out)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+sqlx_text!(RegistrationAuthority);
+
+#[cfg(feature = "schemars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+impl schemars::JsonSchema for RegistrationAuthority {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "RegistrationAuthority".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let codes: Vec<_> = Self::all().map(|ra| ra.as_str()).collect();
+        schemars::json_schema!({
+            "type": "string",
+            "description": "The code of a registration authority from the GLEIF Registration Authorities List.",
+            "enum": codes,
+            "examples": ["RA000548"],
+        })
+    }
+}
+
+#[cfg(feature = "utoipa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utoipa")))]
+impl utoipa::PartialSchema for RegistrationAuthority {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        utoipa::openapi::ObjectBuilder::new()
+            .schema_type(utoipa::openapi::Type::String)
+            .description(Some(
+                "The code of a registration authority from the GLEIF Registration Authorities List.",
+            ))
+            .enum_values(Some(Self::all().map(|ra| ra.as_str())))
+            .examples(["RA000548"])
+            .into()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utoipa")))]
+impl utoipa::ToSchema for RegistrationAuthority {}
+
+impl std::fmt::Display for RegistrationAuthority {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
     }
 }
 
@@ -27,15 +171,36 @@ impl TryFrom<&str> for RegistrationAuthority {
     }
 }
 
+// Returns the index of the first occurrence of `s`, so that registration
+// authorities listed more than once compare equal.
 #[must_use]
 fn ra_index(s: &str) -> Option<usize> {
-    REGISTRATION_AUTHORITIES.binary_search(&s).ok()
+    let index = REGISTRATION_AUTHORITIES.partition_point(|&ra| ra < s);
+    (REGISTRATION_AUTHORITIES.get(index) == Some(&s)).then_some(index)
 }
 
+// The numeric parts of `REGISTRATION_AUTHORITIES`, which are all of the
+// form `RA` followed by 6 digits. Being static, they can be passed by
+// reference to `ToSql`.
+static RA_NUMBERS: [i32; REGISTRATION_AUTHORITIES.len()] = {
+    let mut numbers = [0; REGISTRATION_AUTHORITIES.len()];
+    let mut i = 0;
+    while i < numbers.len() {
+        let code = REGISTRATION_AUTHORITIES[i].as_bytes();
+        let mut j = 2;
+        while j < code.len() {
+            numbers[i] = numbers[i] * 10 + (code[j] - b'0') as i32;
+            j += 1;
+        }
+        i += 1;
+    }
+    numbers
+};
+
 // extracted from
 // <https://www.gleif.org/about-lei/code-lists/gleif-registration-authorities-list/2022-03-23_ra_list_v1.7.xlsx>
 // This list must be sorted for the above `ra_index` function to work.
-const REGISTRATION_AUTHORITIES: [&str; 1037] = [
+static REGISTRATION_AUTHORITIES: [&str; 1037] = [
     "RA000001", "RA000002", "RA000003", "RA000004", "RA000005", "RA000006", "RA000007", "RA000008",
     "RA000009", "RA000010", "RA000011", "RA000012", "RA000013", "RA000014", "RA000015", "RA000016",
     "RA000017", "RA000018", "RA000019", "RA000020", "RA000021", "RA000022", "RA000023", "RA000024",
@@ -202,6 +367,90 @@ mod tests {
     fn test_registration_authority() {
         let ra: RegistrationAuthority = "RA000094".try_into().unwrap();
         assert_tokens(&ra, &[Token::BorrowedStr("RA000094")]);
+        assert_eq!(ra.to_string(), "RA000094");
+    }
+
+    #[test]
+    fn test_number() {
+        let ra = RegistrationAuthority::try_from("RA000548").unwrap();
+        assert_eq!(ra.number(), 548);
+        assert_eq!(RegistrationAuthority::from_number(548), Ok(ra));
+        assert_eq!(
+            RegistrationAuthority::from_number(100001),
+            Err(crate::Error::UnknownRegistrationAuthority(
+                "RA100001".into()
+            ))
+        );
+        for (code, number) in REGISTRATION_AUTHORITIES.iter().zip(RA_NUMBERS) {
+            assert_eq!(code[2..].parse(), Ok(number));
+        }
+    }
+
+    #[cfg(feature = "diesel")]
+    #[test]
+    fn test_diesel() {
+        use diesel::sql_types::{Integer, Text};
+        use diesel::{Connection, IntoSql, RunQueryDsl};
+
+        let mut conn = diesel::SqliteConnection::establish(":memory:").unwrap();
+        let ra = RegistrationAuthority::try_from("RA000548").unwrap();
+        let text: RegistrationAuthority = diesel::select(ra.clone().into_sql::<Text>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(text, ra);
+        let number: i32 = diesel::select(ra.clone().into_sql::<Integer>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(number, 548);
+        let integer: RegistrationAuthority = diesel::select(ra.clone().into_sql::<Integer>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(integer, ra);
+
+        assert!(diesel::select(diesel::dsl::sql::<Text>("'RA100001'"))
+            .get_result::<RegistrationAuthority>(&mut conn)
+            .is_err());
+        assert!(diesel::select(diesel::dsl::sql::<Integer>("100001"))
+            .get_result::<RegistrationAuthority>(&mut conn)
+            .is_err());
+    }
+
+    #[test]
+    fn test_all() {
+        let all: Vec<_> = RegistrationAuthority::all().collect();
+        let mut codes: Vec<_> = all.iter().map(RegistrationAuthority::as_str).collect();
+        codes.dedup();
+        assert_eq!(codes.len(), all.len());
+        assert!(codes.len() < REGISTRATION_AUTHORITIES.len());
+        assert_eq!(all[0].as_str(), REGISTRATION_AUTHORITIES[0]);
+        for ra in all {
+            assert_eq!(RegistrationAuthority::try_from(ra.as_str()), Ok(ra.clone()));
+            assert_eq!(RegistrationAuthority::from_number(ra.number()), Ok(ra));
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_schemars() {
+        let schema = schemars::schema_for!(RegistrationAuthority).to_value();
+        let codes = schema["enum"].as_array().unwrap();
+        let unique: std::collections::HashSet<_> = codes.iter().collect();
+        assert_eq!(unique.len(), codes.len());
+        assert_eq!(codes.len(), RegistrationAuthority::all().count());
+        assert!(codes.contains(&"RA000548".into()));
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn test_utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(RegistrationAuthority::name(), "RegistrationAuthority");
+        let schema = serde_json::to_value(RegistrationAuthority::schema()).unwrap();
+        assert_eq!(schema["type"], "string");
+        let codes = schema["enum"].as_array().unwrap();
+        assert_eq!(codes.len(), RegistrationAuthority::all().count());
+        assert!(codes.contains(&"RA000548".into()));
     }
 
     #[test]
@@ -0,0 +1,318 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::record::LeiRecord;
+use crate::LEI;
+
+/// A source of LEI records.
+///
+/// Implemented by the GLEIF API clients, the offline golden copy index
+/// and the [`CachingResolver`] decorator wrapping any of them.
+pub trait LeiResolver {
+    /// The error emitted when the source cannot be queried.
+    type Error;
+
+    /// Looks up the record of `lei`. Returns `None` if the LEI is not
+    /// known to the source.
+    fn resolve(&self, lei: &LEI) -> Result<Option<LeiRecord>, Self::Error>;
+}
+
+impl<R: LeiResolver + ?Sized> LeiResolver for &R {
+    type Error = R::Error;
+    fn resolve(&self, lei: &LEI) -> Result<Option<LeiRecord>, Self::Error> {
+        (**self).resolve(lei)
+    }
+}
+
+/// A storage for the lookups cached by a [`CachingResolver`].
+pub trait CacheBackend {
+    /// Returns the cached lookup of `lei` unless it is missing or has
+    /// expired. `Some(None)` is a cached lookup of an unknown LEI.
+    fn get(&self, lei: &LEI) -> Option<Option<LeiRecord>>;
+
+    /// Caches the lookup of `lei` for the duration `ttl`. A `record` of
+    /// `None` marks the LEI as unknown.
+    fn insert(&self, lei: LEI, record: Option<LeiRecord>, ttl: Duration);
+}
+
+/// A [`LeiResolver`] decorator which caches the lookups of another
+/// resolver.
+///
+/// ```
+/// use std::time::Duration;
+/// use leim::resolver::{CachingResolver, LeiResolver, MemoryCache};
+/// # struct Source;
+/// # impl LeiResolver for Source {
+/// #     type Error = std::convert::Infallible;
+/// #     fn resolve(&self, _: &leim::LEI) -> Result<Option<leim::record::LeiRecord>, Self::Error> {
+/// #         Ok(None)
+/// #     }
+/// # }
+///
+/// let resolver = CachingResolver::new(
+///     Source,
+///     MemoryCache::new(10_000),
+///     Duration::from_secs(24 * 60 * 60),
+///     Some(Duration::from_secs(60 * 60)),
+/// );
+/// let lei = "5493000IBP32UQZ0KL24".try_into().unwrap();
+/// assert_eq!(resolver.resolve(&lei), Ok(None));
+/// ```
+#[derive(Debug)]
+pub struct CachingResolver<R, B> {
+    inner: R,
+    backend: B,
+    ttl: Duration,
+    negative_ttl: Option<Duration>,
+}
+
+impl<R: LeiResolver, B: CacheBackend> CachingResolver<R, B> {
+    /// Wraps `inner`, caching records in `backend` for the duration
+    /// `ttl`. Unknown LEIs are cached for `negative_ttl`, or not at all
+    /// if it is `None`.
+    pub fn new(inner: R, backend: B, ttl: Duration, negative_ttl: Option<Duration>) -> Self {
+        Self {
+            inner,
+            backend,
+            ttl,
+            negative_ttl,
+        }
+    }
+
+    /// Returns the wrapped resolver.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: LeiResolver, B: CacheBackend> LeiResolver for CachingResolver<R, B> {
+    type Error = R::Error;
+    fn resolve(&self, lei: &LEI) -> Result<Option<LeiRecord>, Self::Error> {
+        if let Some(cached) = self.backend.get(lei) {
+            return Ok(cached);
+        }
+        let record = self.inner.resolve(lei)?;
+        match (&record, self.negative_ttl) {
+            (Some(_), _) => self.backend.insert(lei.clone(), record.clone(), self.ttl),
+            (None, Some(ttl)) => self.backend.insert(lei.clone(), None, ttl),
+            (None, None) => {}
+        }
+        Ok(record)
+    }
+}
+
+/// An in-memory [`CacheBackend`] holding at most a fixed number of
+/// lookups. When full, the least recently used lookup is evicted.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    state: Mutex<MemoryCacheState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryCacheState {
+    entries: HashMap<LEI, MemoryCacheEntry>,
+    // Maps the last access tick of every entry to its key, the first
+    // element being the least recently used one.
+    recency: BTreeMap<u64, LEI>,
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct MemoryCacheEntry {
+    record: Option<LeiRecord>,
+    expires_at: Instant,
+    last_access: u64,
+}
+
+impl MemoryCache {
+    /// Constructs a cache holding at most `capacity` lookups.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Returns the number of cached lookups, including expired ones
+    /// which have not been evicted yet.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Returns `true` if the cache holds no lookups.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl MemoryCacheState {
+    fn touch(&mut self, lei: &LEI) {
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(lei) {
+            self.recency.remove(&entry.last_access);
+            entry.last_access = self.tick;
+            self.recency.insert(self.tick, lei.clone());
+        }
+    }
+
+    fn remove(&mut self, lei: &LEI) {
+        if let Some(entry) = self.entries.remove(lei) {
+            self.recency.remove(&entry.last_access);
+        }
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get(&self, lei: &LEI) -> Option<Option<LeiRecord>> {
+        let mut state = self.state.lock().unwrap();
+        if state.entries.get(lei)?.expires_at <= Instant::now() {
+            state.remove(lei);
+            return None;
+        }
+        state.touch(lei);
+        state.entries.get(lei).map(|entry| entry.record.clone())
+    }
+
+    fn insert(&self, lei: LEI, record: Option<LeiRecord>, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        state.remove(&lei);
+        while state.entries.len() >= self.capacity {
+            let (_, lru) = state.recency.pop_first().unwrap();
+            state.entries.remove(&lru);
+        }
+        state.tick += 1;
+        let tick = state.tick;
+        state.recency.insert(tick, lei.clone());
+        state.entries.insert(
+            lei,
+            MemoryCacheEntry {
+                record,
+                expires_at: Instant::now() + ttl,
+                last_access: tick,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    pub(crate) fn record(lei: &str) -> LeiRecord {
+        LeiRecord {
+            lei: lei.try_into().unwrap(),
+            legal_name: "Example AG".into(),
+            jurisdiction: Some("CH".into()),
+            entity_status: crate::status::EntityStatus::Active,
+            registration_status: crate::status::RegistrationStatus::Issued,
+            managing_lou: "5299000J2N45DDNE4Y28".try_into().unwrap(),
+            successor_lei: None,
+        }
+    }
+
+    /// A resolver serving fixed records and counting its lookups.
+    #[derive(Default)]
+    pub(crate) struct StaticResolver {
+        pub(crate) records: HashMap<LEI, LeiRecord>,
+        pub(crate) lookups: RefCell<usize>,
+    }
+
+    impl StaticResolver {
+        pub(crate) fn new(records: impl IntoIterator<Item = LeiRecord>) -> Self {
+            Self {
+                records: records
+                    .into_iter()
+                    .map(|record| (record.lei.clone(), record))
+                    .collect(),
+                lookups: RefCell::default(),
+            }
+        }
+    }
+
+    impl LeiResolver for StaticResolver {
+        type Error = std::convert::Infallible;
+        fn resolve(&self, lei: &LEI) -> Result<Option<LeiRecord>, Self::Error> {
+            *self.lookups.borrow_mut() += 1;
+            Ok(self.records.get(lei).cloned())
+        }
+    }
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    #[test]
+    fn test_caching() {
+        let known = record("5493000IBP32UQZ0KL24");
+        let unknown = LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        let resolver = CachingResolver::new(
+            StaticResolver::new([known.clone()]),
+            MemoryCache::new(10),
+            HOUR,
+            Some(HOUR),
+        );
+        for _ in 0..3 {
+            assert_eq!(resolver.resolve(&known.lei), Ok(Some(known.clone())));
+            assert_eq!(resolver.resolve(&unknown), Ok(None));
+        }
+        assert_eq!(*resolver.inner.lookups.borrow(), 2);
+    }
+
+    #[test]
+    fn test_no_negative_caching() {
+        let unknown = LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        let resolver =
+            CachingResolver::new(StaticResolver::default(), MemoryCache::new(10), HOUR, None);
+        for _ in 0..3 {
+            assert_eq!(resolver.resolve(&unknown), Ok(None));
+        }
+        assert_eq!(*resolver.inner.lookups.borrow(), 3);
+        assert!(resolver.backend.is_empty());
+    }
+
+    #[test]
+    fn test_ttl() {
+        let known = record("5493000IBP32UQZ0KL24");
+        let resolver = CachingResolver::new(
+            StaticResolver::new([known.clone()]),
+            MemoryCache::new(10),
+            Duration::ZERO,
+            Some(Duration::ZERO),
+        );
+        assert_eq!(resolver.resolve(&known.lei), Ok(Some(known.clone())));
+        assert_eq!(resolver.resolve(&known.lei), Ok(Some(known)));
+        assert_eq!(*resolver.inner.lookups.borrow(), 2);
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let cache = MemoryCache::new(2);
+        let [a, b, c] = [
+            "5493000IBP32UQZ0KL24",
+            "2594007XIACKNMUAW223",
+            "213800WSGIIZCXF1P572",
+        ]
+        .map(|lei| LEI::try_from(lei).unwrap());
+        cache.insert(a.clone(), None, HOUR);
+        cache.insert(b.clone(), None, HOUR);
+        // Accessing `a` makes `b` the least recently used entry.
+        assert_eq!(cache.get(&a), Some(None));
+        cache.insert(c.clone(), None, HOUR);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&a), Some(None));
+        assert_eq!(cache.get(&b), None);
+        assert_eq!(cache.get(&c), Some(None));
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let cache = MemoryCache::new(0);
+        let lei = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+        cache.insert(lei.clone(), None, HOUR);
+        assert_eq!(cache.get(&lei), None);
+    }
+}
@@ -0,0 +1,464 @@
+use crate::record::LeiRecord;
+
+// Defines a status enum with its textual LEI-CDF representation, the
+// conversions from and to strings and the diesel mapping to `Text`.
+macro_rules! status_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $string:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[cfg_attr(
+            feature = "diesel",
+            derive(diesel::deserialize::FromSqlRow, diesel::expression::AsExpression)
+        )]
+        #[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+        #[cfg_attr(
+            feature = "async-graphql-7",
+            derive(async_graphql_7::Enum),
+            graphql(crate = "async_graphql_7")
+        )]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                #[serde(rename = $string)]
+                #[cfg_attr(feature = "async-graphql-7", graphql(name = $string))]
+                $variant,
+            )*
+        }
+
+        // The derives of both versions read the same `graphql` attributes,
+        // but the async-graphql 5 one rejects the crate path async-graphql
+        // 7 needs. It is thus derived for a private copy of the enum, to
+        // which the async-graphql 5 traits delegate.
+        #[cfg(feature = "async-graphql-5")]
+        const _: () = {
+            // See `graphql::v5`.
+            #[allow(unused_imports)]
+            use async_graphql_5 as async_graphql_7;
+            use async_graphql_5::parser::types::Field;
+            use async_graphql_5::registry::Registry;
+            use async_graphql_5::{
+                ContextSelectionSet, InputType, InputValueResult, OutputType, Positioned,
+                ServerResult, Value,
+            };
+            use std::borrow::Cow;
+
+            $(#[$meta])*
+            #[derive(Clone, Copy, PartialEq, Eq, async_graphql_5::Enum)]
+            enum $name {
+                $(
+                    $(#[$variant_meta])*
+                    #[graphql(name = $string)]
+                    $variant,
+                )*
+            }
+
+            impl From<self::$name> for $name {
+                fn from(status: self::$name) -> Self {
+                    match status {
+                        $(self::$name::$variant => Self::$variant,)*
+                    }
+                }
+            }
+
+            impl From<$name> for self::$name {
+                fn from(status: $name) -> Self {
+                    match status {
+                        $($name::$variant => Self::$variant,)*
+                    }
+                }
+            }
+
+            #[cfg_attr(docsrs, doc(cfg(feature = "async-graphql-5")))]
+            impl InputType for self::$name {
+                type RawValueType = Self;
+
+                fn type_name() -> Cow<'static, str> {
+                    <$name as InputType>::type_name()
+                }
+
+                fn create_type_info(registry: &mut Registry) -> String {
+                    <$name as InputType>::create_type_info(registry)
+                }
+
+                fn parse(value: Option<Value>) -> InputValueResult<Self> {
+                    <$name as InputType>::parse(value)
+                        .map(Into::into)
+                        .map_err(|err| err.propagate())
+                }
+
+                fn to_value(&self) -> Value {
+                    $name::from(*self).to_value()
+                }
+
+                fn as_raw_value(&self) -> Option<&Self> {
+                    Some(self)
+                }
+            }
+
+            #[cfg_attr(docsrs, doc(cfg(feature = "async-graphql-5")))]
+            #[async_graphql_5::async_trait::async_trait]
+            impl OutputType for self::$name {
+                fn type_name() -> Cow<'static, str> {
+                    <$name as OutputType>::type_name()
+                }
+
+                fn create_type_info(registry: &mut Registry) -> String {
+                    <$name as OutputType>::create_type_info(registry)
+                }
+
+                async fn resolve(
+                    &self,
+                    ctx: &ContextSelectionSet<'_>,
+                    field: &Positioned<Field>,
+                ) -> ServerResult<Value> {
+                    $name::from(*self).resolve(ctx, field).await
+                }
+            }
+        };
+
+        impl $name {
+            /// All variants, in the order of their declaration.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Returns the LEI-CDF representation of this status.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $string,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = crate::Error;
+            fn try_from(from: &str) -> Result<Self, Self::Error> {
+                match from {
+                    $($string => Ok(Self::$variant),)*
+                    _ => Err(crate::Error::UnknownStatus(from.into())),
+                }
+            }
+        }
+
+        #[cfg(feature = "diesel")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for $name
+        where
+            DB: diesel::backend::Backend,
+            String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+                Ok(Self::try_from(String::from_sql(bytes)?.as_str())?)
+            }
+        }
+
+        #[cfg(feature = "diesel")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for $name
+        where
+            DB: diesel::backend::Backend,
+            str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut diesel::serialize::Output<'b, '_, DB>,
+            ) -> diesel::serialize::Result {
+                self.as_str().to_sql(out)
+            }
+        }
+    };
+}
+
+status_enum! {
+    /// The status of a legal entity.
+    pub enum EntityStatus {
+        /// The legal entity is operating.
+        Active => "ACTIVE",
+        /// The legal entity has ceased operation.
+        Inactive => "INACTIVE",
+        /// The status is not applicable, e.g. for registrations which
+        /// have not been validated yet.
+        Null => "NULL",
+    }
+}
+
+status_enum! {
+    /// The status of a LEI registration.
+    pub enum RegistrationStatus {
+        /// The registration has been submitted and awaits validation.
+        PendingValidation => "PENDING_VALIDATION",
+        /// The LEI has been issued and is maintained by its LOU.
+        Issued => "ISSUED",
+        /// The registration duplicates another one and has been
+        /// superseded by it.
+        Duplicate => "DUPLICATE",
+        /// The registration has not been renewed in time.
+        Lapsed => "LAPSED",
+        /// The legal entity has merged into another legal entity.
+        Merged => "MERGED",
+        /// The legal entity has ceased operation or the registration
+        /// has been retired on request.
+        Retired => "RETIRED",
+        /// The registration has been found to be erroneous or invalid.
+        Annulled => "ANNULLED",
+        /// The registration has been abandoned before the LEI was
+        /// issued.
+        Cancelled => "CANCELLED",
+        /// The registration has been transferred to another LOU.
+        Transferred => "TRANSFERRED",
+        /// The registration is about to be transferred from another
+        /// LOU.
+        PendingTransfer => "PENDING_TRANSFER",
+        /// The registration is about to be transferred to another LOU.
+        PendingArchival => "PENDING_ARCHIVAL",
+    }
+}
+
+impl EntityStatus {
+    /// Returns `true` if a record of this status may be updated to a
+    /// record of `next` status.
+    ///
+    /// An inactive entity cannot become active again and a known status
+    /// cannot be reset to `NULL`.
+    pub fn can_transition_to(self, next: Self) -> bool {
+        use EntityStatus::*;
+        matches!(
+            (self, next),
+            (Null, _) | (Active, Active | Inactive) | (Inactive, Inactive)
+        )
+    }
+}
+
+impl RegistrationStatus {
+    /// Returns `true` if a record of this status may be updated to a
+    /// record of `next` status, following the GLEIF state transition
+    /// rules. Keeping the status is always allowed.
+    ///
+    /// See <https://www.gleif.org/en/about-lei/gleif-management-of-the-global-lei-system/gleif-state-transition-and-validation-rules>
+    pub fn can_transition_to(self, next: Self) -> bool {
+        use RegistrationStatus::*;
+        self == next
+            || match self {
+                PendingValidation => matches!(next, Issued | Cancelled),
+                Issued | Lapsed => matches!(
+                    next,
+                    Issued
+                        | Lapsed
+                        | Duplicate
+                        | Merged
+                        | Retired
+                        | Annulled
+                        | PendingTransfer
+                        | PendingArchival
+                ),
+                PendingTransfer => matches!(next, Issued | Lapsed | PendingArchival),
+                PendingArchival => matches!(next, Issued | Lapsed | Transferred),
+                Duplicate | Merged | Retired | Annulled | Cancelled | Transferred => false,
+            }
+    }
+
+    /// Returns `true` if no transition out of this status exists.
+    pub fn is_terminal(self) -> bool {
+        Self::ALL
+            .iter()
+            .all(|&next| next == self || !self.can_transition_to(next))
+    }
+
+    /// Returns `true` if the registration has been superseded, in which
+    /// case the record may name a successor LEI.
+    pub fn is_superseded(self) -> bool {
+        use RegistrationStatus::*;
+        matches!(self, Duplicate | Merged | Retired)
+    }
+
+    /// Returns `true` if a LEI of this status may be used to identify
+    /// a counterparty in regulatory reporting, i.e. it has been issued
+    /// and has neither been retired nor superseded. Lapsed LEIs remain
+    /// usable, as their entity still exists.
+    pub fn is_usable_for_reporting(self) -> bool {
+        use RegistrationStatus::*;
+        matches!(self, Issued | Lapsed | PendingTransfer | PendingArchival)
+    }
+}
+
+/// The errors emitted when validating the update of a LEI record.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TransitionError {
+    /// The two records belong to different LEIs.
+    #[error("records of different LEIs: {0} and {1}")]
+    LeiMismatch(crate::LEI, crate::LEI),
+    /// The entity status changed illegally.
+    #[error("illegal entity status transition from {0} to {1}")]
+    EntityStatus(EntityStatus, EntityStatus),
+    /// The registration status changed illegally.
+    #[error("illegal registration status transition from {0} to {1}")]
+    RegistrationStatus(RegistrationStatus, RegistrationStatus),
+}
+
+/// Checks whether `next` is a legal update of the record `previous`.
+pub fn validate_transition(previous: &LeiRecord, next: &LeiRecord) -> Result<(), TransitionError> {
+    if previous.lei != next.lei {
+        return Err(TransitionError::LeiMismatch(
+            previous.lei.clone(),
+            next.lei.clone(),
+        ));
+    }
+    if !previous.entity_status.can_transition_to(next.entity_status) {
+        return Err(TransitionError::EntityStatus(
+            previous.entity_status,
+            next.entity_status,
+        ));
+    }
+    if !previous
+        .registration_status
+        .can_transition_to(next.registration_status)
+    {
+        return Err(TransitionError::RegistrationStatus(
+            previous.registration_status,
+            next.registration_status,
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn test_strings() {
+        for &status in RegistrationStatus::ALL {
+            assert_eq!(RegistrationStatus::try_from(status.as_str()), Ok(status));
+        }
+        for &status in EntityStatus::ALL {
+            assert_eq!(EntityStatus::try_from(status.as_str()), Ok(status));
+        }
+        assert_eq!(
+            RegistrationStatus::try_from("issued"),
+            Err(crate::Error::UnknownStatus("issued".into()))
+        );
+        assert_tokens(
+            &RegistrationStatus::PendingValidation,
+            &[Token::UnitVariant {
+                name: "RegistrationStatus",
+                variant: "PENDING_VALIDATION",
+            }],
+        );
+    }
+
+    #[test]
+    fn test_registration_transitions() {
+        use RegistrationStatus::*;
+        assert!(PendingValidation.can_transition_to(Issued));
+        assert!(Issued.can_transition_to(Lapsed));
+        assert!(Lapsed.can_transition_to(Issued));
+        assert!(Issued.can_transition_to(Merged));
+        assert!(PendingArchival.can_transition_to(Transferred));
+        assert!(!PendingValidation.can_transition_to(Lapsed));
+        assert!(!Issued.can_transition_to(PendingValidation));
+        assert!(!Retired.can_transition_to(Issued));
+        assert!(!Issued.can_transition_to(Transferred));
+
+        let terminal: Vec<_> = RegistrationStatus::ALL
+            .iter()
+            .filter(|s| s.is_terminal())
+            .collect();
+        assert_eq!(
+            terminal,
+            [
+                &Duplicate,
+                &Merged,
+                &Retired,
+                &Annulled,
+                &Cancelled,
+                &Transferred
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entity_transitions() {
+        use EntityStatus::*;
+        assert!(Null.can_transition_to(Active));
+        assert!(Active.can_transition_to(Inactive));
+        assert!(!Inactive.can_transition_to(Active));
+        assert!(!Active.can_transition_to(Null));
+    }
+
+    #[test]
+    fn test_superseded() {
+        let superseded: Vec<_> = RegistrationStatus::ALL
+            .iter()
+            .filter(|s| s.is_superseded())
+            .collect();
+        assert_eq!(
+            superseded,
+            [
+                &RegistrationStatus::Duplicate,
+                &RegistrationStatus::Merged,
+                &RegistrationStatus::Retired
+            ]
+        );
+    }
+
+    #[test]
+    fn test_usable_for_reporting() {
+        use RegistrationStatus::*;
+        assert!(Issued.is_usable_for_reporting());
+        assert!(Lapsed.is_usable_for_reporting());
+        assert!(!PendingValidation.is_usable_for_reporting());
+        assert!(!Retired.is_usable_for_reporting());
+    }
+
+    #[test]
+    fn test_validate_transition() {
+        let previous = crate::resolver::tests::record("5493000IBP32UQZ0KL24");
+        let mut next = previous.clone();
+        next.registration_status = RegistrationStatus::Lapsed;
+        assert_eq!(validate_transition(&previous, &next), Ok(()));
+        assert_eq!(
+            validate_transition(&next, &previous),
+            Ok(()),
+            "lapsed LEIs can be renewed"
+        );
+
+        next.registration_status = RegistrationStatus::Cancelled;
+        assert_eq!(
+            validate_transition(&previous, &next),
+            Err(TransitionError::RegistrationStatus(
+                RegistrationStatus::Issued,
+                RegistrationStatus::Cancelled
+            ))
+        );
+
+        next = previous.clone();
+        next.entity_status = EntityStatus::Null;
+        assert_eq!(
+            validate_transition(&previous, &next),
+            Err(TransitionError::EntityStatus(
+                EntityStatus::Active,
+                EntityStatus::Null
+            ))
+        );
+
+        next = crate::resolver::tests::record("2594007XIACKNMUAW223");
+        assert!(matches!(
+            validate_transition(&previous, &next),
+            Err(TransitionError::LeiMismatch(..))
+        ));
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+use crate::record::LeiRecord;
+use crate::resolver::LeiResolver;
+use crate::status::EntityStatus;
+use crate::LEI;
+
+/// The errors emitted when following a successor chain.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error<E> {
+    /// The resolver failed.
+    #[error("resolver error: {0}")]
+    Resolver(E),
+    /// A LEI of the chain is not known to the resolver. The LEIs of the
+    /// chain are listed in order, the last one being the unknown LEI.
+    #[error("unknown LEI: {}", display_chain(.0))]
+    UnknownLei(Vec<LEI>),
+    /// The chain contains a loop. The LEIs of the chain are listed in
+    /// order, the last one being the first repeated LEI.
+    #[error("successor loop: {}", display_chain(.0))]
+    Loop(Vec<LEI>),
+    /// The chain ends in a superseded registration which doesn't name
+    /// a successor. The LEIs of the chain are listed in order.
+    #[error("no successor: {}", display_chain(.0))]
+    NoSuccessor(Vec<LEI>),
+    /// The chain ends in a registration which is not superseded but
+    /// can't be used for reporting either, e.g. because it was annulled
+    /// or its entity is inactive. The LEIs of the chain are listed in
+    /// order.
+    #[error("no active successor: {}", display_chain(.0))]
+    NotActive(Vec<LEI>),
+}
+
+fn display_chain(chain: &[LEI]) -> String {
+    chain
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// The records visited when following the successors of a LEI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuccessorChain {
+    records: Vec<LeiRecord>,
+}
+
+impl SuccessorChain {
+    /// Returns the record at the end of the chain, which is active and
+    /// usable for reporting.
+    pub fn current(&self) -> &LeiRecord {
+        self.records.last().unwrap()
+    }
+
+    /// Returns all records of the chain in order, starting with the
+    /// record of the resolved LEI and ending with the current one.
+    pub fn records(&self) -> &[LeiRecord] {
+        &self.records
+    }
+
+    /// Returns `true` if the resolved LEI is current itself.
+    pub fn is_current(&self) -> bool {
+        self.records.len() == 1
+    }
+}
+
+/// Follows the successors of `lei` until reaching a registration that
+/// is not superseded (see [`RegistrationStatus::is_superseded`]). Fails
+/// with [`Error::NotActive`] unless that registration is usable for
+/// reporting and its entity is active.
+///
+/// [`RegistrationStatus::is_superseded`]: crate::status::RegistrationStatus::is_superseded
+///
+/// ```
+/// # use leim::{resolver::LeiResolver, record::LeiRecord, LEI};
+/// # struct Retired;
+/// # impl LeiResolver for Retired {
+/// #     type Error = std::convert::Infallible;
+/// #     fn resolve(&self, lei: &LEI) -> Result<Option<LeiRecord>, Self::Error> {
+/// #         let active = LEI::try_from("5493000IBP32UQZ0KL24").unwrap();
+/// #         Ok(Some(LeiRecord {
+/// #             lei: lei.clone(),
+/// #             legal_name: "Example AG".into(),
+/// #             jurisdiction: None,
+/// #             entity_status: leim::status::EntityStatus::Active,
+/// #             registration_status: if *lei == active {
+/// #                 leim::status::RegistrationStatus::Issued
+/// #             } else {
+/// #                 leim::status::RegistrationStatus::Retired
+/// #             },
+/// #             managing_lou: "5299000J2N45DDNE4Y28".try_into().unwrap(),
+/// #             successor_lei: (*lei != active).then_some(active),
+/// #         }))
+/// #     }
+/// # }
+/// # let resolver = Retired;
+/// let retired = "2594007XIACKNMUAW223".try_into().unwrap();
+/// let chain = leim::successor::resolve(&resolver, &retired).unwrap();
+/// assert_eq!(chain.current().lei.to_string(), "5493000IBP32UQZ0KL24");
+/// ```
+pub fn resolve<R: LeiResolver>(resolver: &R, lei: &LEI) -> Result<SuccessorChain, Error<R::Error>> {
+    let mut records: Vec<LeiRecord> = Vec::new();
+    let mut visited = HashSet::new();
+    let mut next = lei.clone();
+    loop {
+        if !visited.insert(next.clone()) {
+            let mut chain: Vec<_> = records.into_iter().map(|r| r.lei).collect();
+            chain.push(next);
+            return Err(Error::Loop(chain));
+        }
+        let Some(record) = resolver.resolve(&next).map_err(Error::Resolver)? else {
+            let mut chain: Vec<_> = records.into_iter().map(|r| r.lei).collect();
+            chain.push(next);
+            return Err(Error::UnknownLei(chain));
+        };
+        let superseded = record.registration_status.is_superseded();
+        let successor = record.successor_lei.clone();
+        records.push(record);
+        match (superseded, successor) {
+            (false, _) => {
+                let current = records.last().unwrap();
+                if current.is_usable_for_reporting()
+                    && current.entity_status == EntityStatus::Active
+                {
+                    return Ok(SuccessorChain { records });
+                }
+                return Err(Error::NotActive(
+                    records.into_iter().map(|r| r.lei).collect(),
+                ));
+            }
+            (true, Some(successor)) => next = successor,
+            (true, None) => {
+                return Err(Error::NoSuccessor(
+                    records.into_iter().map(|r| r.lei).collect(),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolver::tests::{record, StaticResolver};
+    use crate::status::RegistrationStatus;
+
+    const A: &str = "5493000IBP32UQZ0KL24";
+    const B: &str = "2594007XIACKNMUAW223";
+    const C: &str = "213800WSGIIZCXF1P572";
+
+    fn superseded(lei: &str, status: RegistrationStatus, successor: Option<&str>) -> LeiRecord {
+        LeiRecord {
+            registration_status: status,
+            successor_lei: successor.map(|lei| lei.try_into().unwrap()),
+            ..record(lei)
+        }
+    }
+
+    fn lei(lei: &str) -> LEI {
+        lei.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_current() {
+        let resolver = StaticResolver::new([record(A)]);
+        let chain = resolve(&resolver, &lei(A)).unwrap();
+        assert!(chain.is_current());
+        assert_eq!(chain.current().lei, lei(A));
+    }
+
+    #[test]
+    fn test_chain() {
+        let resolver = StaticResolver::new([
+            superseded(A, RegistrationStatus::Merged, Some(B)),
+            superseded(B, RegistrationStatus::Duplicate, Some(C)),
+            record(C),
+        ]);
+        let chain = resolve(&resolver, &lei(A)).unwrap();
+        assert!(!chain.is_current());
+        assert_eq!(chain.current().lei, lei(C));
+        assert_eq!(
+            chain
+                .records()
+                .iter()
+                .map(|r| r.lei.clone())
+                .collect::<Vec<_>>(),
+            [lei(A), lei(B), lei(C)]
+        );
+    }
+
+    #[test]
+    fn test_loop() {
+        let resolver = StaticResolver::new([
+            superseded(A, RegistrationStatus::Merged, Some(B)),
+            superseded(B, RegistrationStatus::Retired, Some(A)),
+        ]);
+        let err = resolve(&resolver, &lei(A)).unwrap_err();
+        assert_eq!(err, Error::Loop(vec![lei(A), lei(B), lei(A)]));
+        assert_eq!(
+            err.to_string(),
+            format!("successor loop: {A} -> {B} -> {A}")
+        );
+    }
+
+    #[test]
+    fn test_dead_ends() {
+        let resolver = StaticResolver::new([
+            superseded(A, RegistrationStatus::Retired, None),
+            superseded(B, RegistrationStatus::Merged, Some(C)),
+        ]);
+        assert_eq!(
+            resolve(&resolver, &lei(A)),
+            Err(Error::NoSuccessor(vec![lei(A)]))
+        );
+        let err = resolve(&resolver, &lei(B)).unwrap_err();
+        assert_eq!(err, Error::UnknownLei(vec![lei(B), lei(C)]));
+        assert_eq!(err.to_string(), format!("unknown LEI: {B} -> {C}"));
+    }
+
+    #[test]
+    fn test_not_active() {
+        let resolver = StaticResolver::new([
+            superseded(A, RegistrationStatus::Merged, Some(B)),
+            superseded(B, RegistrationStatus::Annulled, None),
+            LeiRecord {
+                entity_status: EntityStatus::Inactive,
+                ..record(C)
+            },
+        ]);
+        assert_eq!(
+            resolve(&resolver, &lei(A)),
+            Err(Error::NotActive(vec![lei(A), lei(B)]))
+        );
+        assert_eq!(
+            resolve(&resolver, &lei(C)),
+            Err(Error::NotActive(vec![lei(C)]))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::Range;
+
+use crate::{Error, LEI};
+
+/// A LEI candidate found in a text.
+#[derive(Debug, PartialEq)]
+pub struct Match<'a> {
+    /// The byte offsets of the candidate within the text.
+    pub span: Range<usize>,
+    /// The candidate itself.
+    pub text: &'a str,
+    /// The result of validating the candidate. An error means the
+    /// candidate is a near-miss, e.g. a LEI with a typo.
+    pub lei: Result<LEI, Error>,
+}
+
+impl Match<'_> {
+    /// Returns whether the candidate is a valid LEI.
+    pub fn is_valid(&self) -> bool {
+        self.lei.is_ok()
+    }
+}
+
+/// Finds the LEIs in `text`, e.g. in a contract or an email.
+///
+/// Candidates are words of 20 uppercase letters and digits ending in two
+/// digits, the check digits. Each candidate is validated, so the
+/// returned matches include near-misses with an invalid checksum.
+///
+/// ```
+/// use leim::text::find_leis;
+///
+/// let text = "Counterparty: 5493000IBP32UQZ0KL24 (formerly 5493000IBP32UQZ0KL25).";
+/// let matches: Vec<_> = find_leis(text).collect();
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(matches[0].span, 14..34);
+/// assert!(matches[0].is_valid());
+/// assert!(!matches[1].is_valid());
+/// ```
+pub fn find_leis(text: &str) -> impl Iterator<Item = Match<'_>> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| is_candidate(word))
+        .map(move |word| {
+            // `word` is a subslice of `text`.
+            let start = word.as_ptr() as usize - text.as_ptr() as usize;
+            Match {
+                span: start..start + word.len(),
+                text: word,
+                lei: word.try_into(),
+            }
+        })
+}
+
+fn is_candidate(word: &str) -> bool {
+    word.len() == 20 && crate::validate_chars(word).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_leis() {
+        let text = "LEI:2594007XIACKNMUAW223,\n\
+            see 54930084UKLVMY22DS16/213800WSGIIZCXF1P572.";
+        let matches: Vec<_> = find_leis(text).collect();
+        assert_eq!(matches.len(), 3);
+        for m in &matches {
+            assert!(m.is_valid());
+            assert_eq!(&text[m.span.clone()], m.text);
+        }
+        assert_eq!(matches[0].span, 4..24);
+        assert_eq!(
+            matches[2].lei,
+            Ok(LEI::try_from("213800WSGIIZCXF1P572").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_near_misses() {
+        let matches: Vec<_> = find_leis("2594007XIACKNMUAW224 2594007XIACKNMUAW").collect();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].lei, Err(Error::InvalidChecksum));
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        for text in [
+            "INTERNATIONALIZATION",
+            "X2594007XIACKNMUAW223",
+            "2594007XIACKNMUAW2231",
+            "é2594007XIACKNMUAW223",
+            "2594007xiacknmuaw223",
+        ] {
+            assert_eq!(find_leis(text).count(), 0, "{text}");
+        }
+        assert_eq!(find_leis("«2594007XIACKNMUAW223»").count(), 1);
+    }
+}
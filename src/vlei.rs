@@ -0,0 +1,260 @@
+use crate::LEI;
+
+/// The errors emitted when parsing a vLEI credential.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The credential is not valid JSON or lacks required fields.
+    #[error("invalid credential: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The credential is not a JSON serialised ACDC.
+    #[error("unsupported version string: {0}")]
+    UnsupportedVersion(String),
+    /// The schema of the credential is not a known vLEI schema.
+    #[error("unknown schema: {0}")]
+    UnknownSchema(String),
+    /// The credential lacks an attribute required by its schema.
+    #[error("missing attribute: {0}")]
+    MissingAttribute(&'static str),
+    /// The credential contains an invalid LEI.
+    #[error("invalid LEI: {0}")]
+    InvalidLei(#[from] crate::Error),
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// The types of vLEI credentials issued in the GLEIF vLEI ecosystem.
+///
+/// See <https://github.com/WebOfTrust/vLEI/tree/main/schema/acdc>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CredentialType {
+    /// A credential issued by GLEIF to a Qualified vLEI Issuer (QVI).
+    QualifiedVleiIssuer,
+    /// A credential issued by a QVI to a legal entity.
+    LegalEntity,
+    /// A credential issued by a QVI to a person in an official
+    /// organizational role (OOR) of a legal entity.
+    OfficialOrganizationalRole,
+    /// A credential issued to a person in an engagement context role
+    /// (ECR) of a legal entity.
+    EngagementContextRole,
+}
+
+impl CredentialType {
+    /// All credential types.
+    pub const ALL: [Self; 4] = [
+        Self::QualifiedVleiIssuer,
+        Self::LegalEntity,
+        Self::OfficialOrganizationalRole,
+        Self::EngagementContextRole,
+    ];
+
+    /// Returns the self-addressing identifier (SAID) of the schema of
+    /// this credential type.
+    pub fn schema_said(self) -> &'static str {
+        match self {
+            Self::QualifiedVleiIssuer => "EBfdlu8R27Fbx-ehrqwImnK-8Cm79sqbAQ4MmvEAYqao",
+            Self::LegalEntity => "ENPXp1vQzRF6JwIuS-mp2U8Uf1MoADoP_GqQ62VsDZWY",
+            Self::OfficialOrganizationalRole => "EBNaNu-M9P5cgrnfl2Fvymy4E_jvxxyjb70PRtiANlJy",
+            Self::EngagementContextRole => "EEy9PkikFcANV1l7EHukCeXqrzT1hNZjGlUk7wuMO5jw",
+        }
+    }
+
+    /// Returns the credential type of the schema `said`, if it is a
+    /// known vLEI schema.
+    pub fn from_schema_said(said: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|credential_type| credential_type.schema_said() == said)
+    }
+}
+
+/// A vLEI credential, i.e. an Authentic Chained Data Container (ACDC)
+/// following one of the vLEI schemas.
+///
+/// Parsing checks the structure of the credential, its schema and the
+/// embedded LEI. It does *not* verify the credential cryptographically,
+/// which requires resolving the KERI key event logs of the issuer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Credential {
+    /// The type of the credential, derived from its schema.
+    pub credential_type: CredentialType,
+    /// The SAID of the credential.
+    pub said: String,
+    /// The autonomic identifier (AID) of the issuer.
+    pub issuer: String,
+    /// The AID of the issuee, if any.
+    pub issuee: Option<String>,
+    /// The issuance date-time, in ISO 8601 format.
+    pub issuance_date: String,
+    /// The LEI of the legal entity, or of the QVI itself for QVI
+    /// credentials.
+    pub lei: LEI,
+    /// The legal name of the role holder for OOR and ECR credentials.
+    pub person_legal_name: Option<String>,
+    /// The official organizational role or engagement context role for
+    /// OOR and ECR credentials.
+    pub role: Option<String>,
+}
+
+// The JSON serialisation of an ACDC, see
+// <https://trustoverip.github.io/tswg-acdc-specification/>. Only the
+// fields needed to construct a `Credential` are decoded.
+
+#[derive(serde::Deserialize)]
+struct Acdc {
+    v: String,
+    d: String,
+    i: String,
+    s: String,
+    a: Attributes,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Attributes {
+    i: Option<String>,
+    dt: String,
+    #[serde(rename = "LEI")]
+    lei: String,
+    person_legal_name: Option<String>,
+    official_role: Option<String>,
+    engagement_context_role: Option<String>,
+}
+
+impl Credential {
+    /// Parses a JSON serialised vLEI credential.
+    pub fn from_json(json: &[u8]) -> Result<Self> {
+        let acdc: Acdc = serde_json::from_slice(json)?;
+        if !acdc.v.starts_with("ACDC10JSON") {
+            return Err(Error::UnsupportedVersion(acdc.v));
+        }
+        let credential_type = CredentialType::from_schema_said(&acdc.s)
+            .ok_or_else(|| Error::UnknownSchema(acdc.s.clone()))?;
+        let attributes = acdc.a;
+        let (person_legal_name, role) = match credential_type {
+            CredentialType::QualifiedVleiIssuer | CredentialType::LegalEntity => (None, None),
+            CredentialType::OfficialOrganizationalRole => (
+                attributes.person_legal_name,
+                Some(
+                    attributes
+                        .official_role
+                        .ok_or(Error::MissingAttribute("officialRole"))?,
+                ),
+            ),
+            CredentialType::EngagementContextRole => (
+                attributes.person_legal_name,
+                Some(
+                    attributes
+                        .engagement_context_role
+                        .ok_or(Error::MissingAttribute("engagementContextRole"))?,
+                ),
+            ),
+        };
+        if role.is_some() && person_legal_name.is_none() {
+            return Err(Error::MissingAttribute("personLegalName"));
+        }
+        Ok(Self {
+            credential_type,
+            said: acdc.d,
+            issuer: acdc.i,
+            issuee: attributes.i,
+            issuance_date: attributes.dt,
+            lei: attributes.lei.as_str().try_into()?,
+            person_legal_name,
+            role,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // An abbreviated OOR credential, edges and rules omitted.
+    const OOR_CREDENTIAL: &str = r#"{
+        "v": "ACDC10JSON000746_",
+        "d": "EIDJ2uvuk0rWR6VCVjiDpyOmzZKzXWHkOmNTQ4ZzHt2O",
+        "i": "EHMnCf8_nIemuPx-cUHaDQq8zSnQIFAurdEpwHpNbnvX",
+        "ri": "EMD_mtv7mINAzDRl3THZSlM0YpXOVnH0SW_x8y4C5x62",
+        "s": "EBNaNu-M9P5cgrnfl2Fvymy4E_jvxxyjb70PRtiANlJy",
+        "a": {
+            "d": "ENPrIaIVrz8HN9zTrWoQE8Wd9CWsAmZhUl6mbhHyYn3G",
+            "i": "EKE7b7owCZo5Jc6S1kzOqYmBMiLnsudzgSd0C58FEqIN",
+            "dt": "2023-11-15T09:12:42.126000+00:00",
+            "personLegalName": "Jane Doe",
+            "officialRole": "Chief Executive Officer",
+            "LEI": "5493000IBP32UQZ0KL24"
+        }
+    }"#;
+
+    #[test]
+    fn test_oor_credential() {
+        let credential = Credential::from_json(OOR_CREDENTIAL.as_bytes()).unwrap();
+        assert_eq!(
+            credential.credential_type,
+            CredentialType::OfficialOrganizationalRole
+        );
+        assert_eq!(credential.lei.to_string(), "5493000IBP32UQZ0KL24");
+        assert_eq!(credential.person_legal_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(credential.role.as_deref(), Some("Chief Executive Officer"));
+        assert_eq!(
+            credential.issuee.as_deref(),
+            Some("EKE7b7owCZo5Jc6S1kzOqYmBMiLnsudzgSd0C58FEqIN")
+        );
+    }
+
+    #[test]
+    fn test_legal_entity_credential() {
+        let json = OOR_CREDENTIAL.replace(
+            CredentialType::OfficialOrganizationalRole.schema_said(),
+            CredentialType::LegalEntity.schema_said(),
+        );
+        let credential = Credential::from_json(json.as_bytes()).unwrap();
+        assert_eq!(credential.credential_type, CredentialType::LegalEntity);
+        assert_eq!(credential.person_legal_name, None);
+        assert_eq!(credential.role, None);
+    }
+
+    #[test]
+    fn test_schema_saids() {
+        for credential_type in CredentialType::ALL {
+            assert_eq!(
+                CredentialType::from_schema_said(credential_type.schema_said()),
+                Some(credential_type)
+            );
+        }
+        assert_eq!(CredentialType::from_schema_said("E"), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        let json = OOR_CREDENTIAL.replace(
+            CredentialType::OfficialOrganizationalRole.schema_said(),
+            "EBNaNu-M9P5cgrnfl2Fvymy4E_jvxxyjb70PRtiANlJz",
+        );
+        assert!(matches!(
+            Credential::from_json(json.as_bytes()),
+            Err(Error::UnknownSchema(_))
+        ));
+
+        let json = OOR_CREDENTIAL.replace("officialRole", "role");
+        assert!(matches!(
+            Credential::from_json(json.as_bytes()),
+            Err(Error::MissingAttribute("officialRole"))
+        ));
+
+        let json = OOR_CREDENTIAL.replace("KL24", "KL25");
+        assert!(matches!(
+            Credential::from_json(json.as_bytes()),
+            Err(Error::InvalidLei(crate::Error::InvalidChecksum))
+        ));
+
+        let json = OOR_CREDENTIAL.replace("ACDC10JSON", "KERI10JSON");
+        assert!(matches!(
+            Credential::from_json(json.as_bytes()),
+            Err(Error::UnsupportedVersion(_))
+        ));
+
+        assert!(matches!(Credential::from_json(b"{}"), Err(Error::Json(_))));
+    }
+}
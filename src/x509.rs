@@ -0,0 +1,362 @@
+use x509_parser::der_parser::oid::Oid;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::LEI;
+
+/// The errors emitted when extracting a LEI from a certificate.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    /// The certificate could not be parsed.
+    #[error("invalid certificate: {0}")]
+    InvalidCertificate(String),
+    /// A LEI or role value is not a DER encoded string.
+    #[error("malformed {0} value")]
+    MalformedValue(&'static str),
+    /// The certificate contains an invalid LEI.
+    #[error("invalid LEI: {0}")]
+    InvalidLei(#[from] crate::Error),
+    /// The certificate contains several different LEIs or roles.
+    #[error("conflicting {0} values")]
+    Conflicting(&'static str),
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// The OID of the LEI extension and subject attribute, as defined in
+/// ISO 17442-2.
+pub const LEI_OID: &[u64] = &[1, 3, 6, 1, 4, 1, 52266, 1];
+
+/// The OID of the role extension and subject attribute, as defined in
+/// ISO 17442-2.
+pub const ROLE_OID: &[u64] = &[1, 3, 6, 1, 4, 1, 52266, 2];
+
+/// The LEI embedded in a certificate, together with the role of the
+/// subject within the legal entity, if given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateLei {
+    /// The LEI of the legal entity the subject belongs to.
+    pub lei: LEI,
+    /// The role of the subject within the legal entity, e.g. `CEO`.
+    pub role: Option<String>,
+}
+
+/// Extracts the LEI and role from a DER encoded X.509 certificate.
+/// Returns `None` if the certificate doesn't contain a LEI.
+///
+/// The LEI and role are taken from both the certificate extensions and
+/// the subject attributes, which must agree if both are present.
+pub fn from_der(der: &[u8]) -> Result<Option<CertificateLei>> {
+    let (_, certificate) =
+        X509Certificate::from_der(der).map_err(|err| Error::InvalidCertificate(err.to_string()))?;
+    from_certificate(&certificate)
+}
+
+/// Extracts the LEI and role from a PEM encoded X.509 certificate, see
+/// [`from_der`].
+pub fn from_pem(pem: &[u8]) -> Result<Option<CertificateLei>> {
+    let (_, pem) = x509_parser::pem::parse_x509_pem(pem)
+        .map_err(|err| Error::InvalidCertificate(err.to_string()))?;
+    from_der(&pem.contents)
+}
+
+/// Extracts the LEI and role from a parsed X.509 certificate, see
+/// [`from_der`].
+pub fn from_certificate(certificate: &X509Certificate) -> Result<Option<CertificateLei>> {
+    let lei_oid = Oid::from(LEI_OID).unwrap();
+    let role_oid = Oid::from(ROLE_OID).unwrap();
+
+    let mut leis = Vec::new();
+    let mut roles = Vec::new();
+    for extension in certificate.extensions() {
+        if extension.oid == lei_oid {
+            leis.push(lei_from_extension_value(extension.value)?);
+        } else if extension.oid == role_oid {
+            roles.push(role_from_extension_value(extension.value)?);
+        }
+    }
+    for attribute in certificate.subject().iter_attributes() {
+        if *attribute.attr_type() == lei_oid {
+            let lei = attribute
+                .as_str()
+                .map_err(|_| Error::MalformedValue("LEI"))?;
+            leis.push(lei.try_into()?);
+        } else if *attribute.attr_type() == role_oid {
+            let role = attribute
+                .as_str()
+                .map_err(|_| Error::MalformedValue("role"))?;
+            roles.push(role.into());
+        }
+    }
+
+    let lei = match single(leis, "LEI")? {
+        Some(lei) => lei,
+        None => return Ok(None),
+    };
+    let role = single(roles, "role")?;
+    Ok(Some(CertificateLei { lei, role }))
+}
+
+/// Decodes the value of a LEI extension, a DER encoded
+/// `PrintableString`.
+pub fn lei_from_extension_value(value: &[u8]) -> Result<LEI> {
+    Ok(decode_string(value, "LEI")?.as_str().try_into()?)
+}
+
+/// Decodes the value of a role extension, a DER encoded
+/// `PrintableString` or `UTF8String`.
+pub fn role_from_extension_value(value: &[u8]) -> Result<String> {
+    decode_string(value, "role")
+}
+
+fn decode_string(value: &[u8], name: &'static str) -> Result<String> {
+    let (rest, object) =
+        x509_parser::der_parser::parse_der(value).map_err(|_| Error::MalformedValue(name))?;
+    if !rest.is_empty() {
+        return Err(Error::MalformedValue(name));
+    }
+    object
+        .as_str()
+        .map(Into::into)
+        .map_err(|_| Error::MalformedValue(name))
+}
+
+/// Encodes the value of a LEI extension, a DER encoded
+/// `PrintableString`. This is the form expected by certificate builders
+/// taking the OID and value of custom extensions separately.
+pub fn lei_extension_value(lei: &LEI) -> Vec<u8> {
+    der(PRINTABLE_STRING, lei.to_string().as_bytes())
+}
+
+/// Encodes the value of a role extension. Roles consisting of
+/// printable characters only are encoded as `PrintableString`, other
+/// roles as `UTF8String`.
+pub fn role_extension_value(role: &str) -> Vec<u8> {
+    let printable = role
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || " '()+,-./:=?".contains(c));
+    let tag = if printable {
+        PRINTABLE_STRING
+    } else {
+        UTF8_STRING
+    };
+    der(tag, role.as_bytes())
+}
+
+/// Encodes the non-critical LEI extension and, if `role` is given, the
+/// role extension, each as a DER encoded X.509 `Extension`, e.g. to be
+/// included in the extension request of a CSR.
+pub fn extensions(lei: &LEI, role: Option<&str>) -> Vec<Vec<u8>> {
+    let mut extensions = vec![extension(LEI_OID, &lei_extension_value(lei))];
+    if let Some(role) = role {
+        extensions.push(extension(ROLE_OID, &role_extension_value(role)));
+    }
+    extensions
+}
+
+const OCTET_STRING: u8 = 0x04;
+const OBJECT_IDENTIFIER: u8 = 0x06;
+const UTF8_STRING: u8 = 0x0c;
+const SEQUENCE: u8 = 0x30;
+const PRINTABLE_STRING: u8 = 0x13;
+
+// Encodes `Extension ::= SEQUENCE { extnID, critical DEFAULT FALSE,
+// extnValue }`, omitting the default criticality.
+fn extension(oid: &[u64], value: &[u8]) -> Vec<u8> {
+    let mut content = der(OBJECT_IDENTIFIER, &oid_content(oid));
+    content.extend(der(OCTET_STRING, value));
+    der(SEQUENCE, &content)
+}
+
+fn oid_content(oid: &[u64]) -> Vec<u8> {
+    let mut content = Vec::new();
+    let arcs = std::iter::once(oid[0] * 40 + oid[1]).chain(oid[2..].iter().copied());
+    for arc in arcs {
+        let mut bytes = vec![(arc & 0x7f) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            bytes.push((rest & 0x7f) as u8 | 0x80);
+            rest >>= 7;
+        }
+        content.extend(bytes.iter().rev());
+    }
+    content
+}
+
+fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut der = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        der.push(len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let skip = bytes.iter().take_while(|b| **b == 0).count();
+        der.push(0x80 | (bytes.len() - skip) as u8);
+        der.extend_from_slice(&bytes[skip..]);
+    }
+    der.extend_from_slice(content);
+    der
+}
+
+// Returns the single distinct value of `values`, if any.
+fn single<T: PartialEq>(mut values: Vec<T>, name: &'static str) -> Result<Option<T>> {
+    let value = values.pop();
+    if values.iter().any(|other| Some(other) != value.as_ref()) {
+        return Err(Error::Conflicting(name));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use x509_parser::prelude::X509Extension;
+
+    const LEI: &str = "5493000IBP32UQZ0KL24";
+
+    fn printable_string(s: &str) -> Vec<u8> {
+        der(PRINTABLE_STRING, s.as_bytes())
+    }
+
+    /// Creates a self-signed certificate with the given extensions and
+    /// subject attributes.
+    fn certificate(
+        extensions: Vec<(&[u64], Vec<u8>)>,
+        attributes: Vec<(&[u64], &str)>,
+    ) -> rcgen::Certificate {
+        let mut params = rcgen::CertificateParams::new(vec!["example.com".into()]).unwrap();
+        for (oid, content) in extensions {
+            params
+                .custom_extensions
+                .push(rcgen::CustomExtension::from_oid_content(oid, content));
+        }
+        for (oid, value) in attributes {
+            params.distinguished_name.push(
+                rcgen::DnType::CustomDnType(oid.to_vec()),
+                rcgen::DnValue::PrintableString(value.try_into().unwrap()),
+            );
+        }
+        let key = rcgen::KeyPair::generate().unwrap();
+        params.self_signed(&key).unwrap()
+    }
+
+    #[test]
+    fn test_extensions() {
+        let cert = certificate(
+            vec![
+                (LEI_OID, printable_string(LEI)),
+                (ROLE_OID, printable_string("CEO")),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            from_der(cert.der()),
+            Ok(Some(CertificateLei {
+                lei: LEI.try_into().unwrap(),
+                role: Some("CEO".into())
+            }))
+        );
+        assert_eq!(
+            from_pem(cert.pem().as_bytes())
+                .unwrap()
+                .unwrap()
+                .lei
+                .to_string(),
+            LEI
+        );
+    }
+
+    #[test]
+    fn test_subject_attributes() {
+        let cert = certificate(vec![], vec![(LEI_OID, LEI)]);
+        assert_eq!(
+            from_der(cert.der()),
+            Ok(Some(CertificateLei {
+                lei: LEI.try_into().unwrap(),
+                role: None
+            }))
+        );
+
+        // Agreeing LEIs are fine.
+        let cert = certificate(vec![(LEI_OID, printable_string(LEI))], vec![(LEI_OID, LEI)]);
+        assert!(from_der(cert.der()).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_without_lei() {
+        let cert = certificate(vec![(ROLE_OID, printable_string("CEO"))], vec![]);
+        assert_eq!(from_der(cert.der()), Ok(None));
+    }
+
+    #[test]
+    fn test_errors() {
+        let cert = certificate(
+            vec![(LEI_OID, printable_string("5493000IBP32UQZ0KL25"))],
+            vec![],
+        );
+        assert_eq!(
+            from_der(cert.der()),
+            Err(Error::InvalidLei(crate::Error::InvalidChecksum))
+        );
+
+        let cert = certificate(vec![(LEI_OID, LEI.as_bytes().to_vec())], vec![]);
+        assert_eq!(from_der(cert.der()), Err(Error::MalformedValue("LEI")));
+
+        let cert = certificate(
+            vec![(LEI_OID, printable_string(LEI))],
+            vec![(LEI_OID, "2594007XIACKNMUAW223")],
+        );
+        assert_eq!(from_der(cert.der()), Err(Error::Conflicting("LEI")));
+
+        assert!(matches!(
+            from_der(b"not a certificate"),
+            Err(Error::InvalidCertificate(_))
+        ));
+    }
+
+    #[test]
+    fn test_extension_values() {
+        let lei = LEI::try_from(LEI).unwrap();
+        let cert = certificate(
+            vec![
+                (LEI_OID, lei_extension_value(&lei)),
+                (ROLE_OID, role_extension_value("Directeur Général")),
+            ],
+            vec![],
+        );
+        assert_eq!(
+            from_der(cert.der()),
+            Ok(Some(CertificateLei {
+                lei,
+                role: Some("Directeur Général".into())
+            }))
+        );
+        assert_eq!(role_extension_value("CEO"), printable_string("CEO"));
+        assert_eq!(role_extension_value("R&D")[0], UTF8_STRING);
+    }
+
+    #[test]
+    fn test_encode_extensions() {
+        let lei = LEI::try_from(LEI).unwrap();
+        let encoded = extensions(&lei, Some("CEO"));
+        assert_eq!(encoded.len(), 2);
+
+        let (rest, extension) = X509Extension::from_der(&encoded[0]).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(extension.oid, Oid::from(LEI_OID).unwrap());
+        assert!(!extension.critical);
+        assert_eq!(lei_from_extension_value(extension.value), Ok(lei.clone()));
+
+        let (_, extension) = X509Extension::from_der(&encoded[1]).unwrap();
+        assert_eq!(extension.oid, Oid::from(ROLE_OID).unwrap());
+        assert_eq!(role_from_extension_value(extension.value), Ok("CEO".into()));
+
+        assert_eq!(extensions(&lei, None).len(), 1);
+    }
+
+    #[test]
+    fn test_der_length() {
+        let long = der(UTF8_STRING, &[b'a'; 300]);
+        assert_eq!(long[..4], [UTF8_STRING, 0x82, 0x01, 0x2c]);
+        assert_eq!(decode_string(&long, "role").unwrap().len(), 300);
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn leim(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_leim"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn json(output: &Output) -> serde_json::Value {
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_validate() {
+    let output = leim(&["validate"], "5493000IBP32UQZ0KL24\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "5493000IBP32UQZ0KL24\tvalid\n");
+
+    let output = leim(
+        &["--json", "validate"],
+        "5493000IBP32UQZ0KL24\n5493000IBP32UQZ0KL25\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        json(&output),
+        serde_json::json!([
+            { "lei": "5493000IBP32UQZ0KL24", "valid": true },
+            { "lei": "5493000IBP32UQZ0KL25", "valid": false, "error": "invalid checksum" },
+        ])
+    );
+}
+
+#[test]
+fn test_validate_csv() {
+    let csv = "Name,LEI\nUBS,5493000IBP32UQZ0KL24\nTypo,5493000IBP32UQZ0KL25\n";
+    let output = leim(&["--json", "validate-csv"], csv);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(json(&output)["summary"]["invalid"], 1);
+}
+
+#[test]
+fn test_inspect() {
+    let output = leim(&["--json", "inspect", "5493000IBP32UQZ0KL24"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        json(&output),
+        serde_json::json!({
+            "lei": "5493000IBP32UQZ0KL24",
+            "louPrefix": "5493",
+            "entityPart": "000IBP32UQZ0KL",
+            "checkDigits": "24",
+        })
+    );
+
+    let output = leim(&["inspect", "5493000IBP32UQZ0KL25"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "error: invalid checksum\n"
+    );
+}
+
+#[test]
+fn test_check_digits() {
+    let output = leim(&["check-digits", "5493000IBP32UQZ0KL"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "5493000IBP32UQZ0KL24\n");
+}
+
+#[test]
+fn test_random() {
+    let output = leim(&["--json", "random", "-c", "3"], "");
+    assert_eq!(output.status.code(), Some(0));
+    let leis: Vec<leim::LEI> = serde_json::from_value(json(&output)).unwrap();
+    assert_eq!(leis.len(), 3);
+}
+
+#[test]
+fn test_ra() {
+    let output = leim(&["--json", "ra", "RA000548"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        json(&output),
+        serde_json::json!({ "code": "RA000548", "known": true, "number": 548 })
+    );
+
+    let output = leim(&["ra", "RA100001"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "RA100001\tunknown registration authority: RA100001\n"
+    );
+}
+
+#[test]
+fn test_suggest() {
+    let output = leim(&["suggest", "5493000IBP32UQZ0KL28"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output)
+        .lines()
+        .any(|lei| lei == "5493000IBP32UQZ0KL24"));
+
+    let output = leim(&["suggest", "5493000IBP32UQZ0Kéx"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+}
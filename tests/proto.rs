@@ -0,0 +1,31 @@
+#![cfg(feature = "prost")]
+
+use std::path::Path;
+
+const GENERATED: &str = "src/proto/leim.v1.rs";
+
+// Regenerates the prost code from `proto/` and compares it with the
+// checked-in code. Set `LEIM_UPDATE_PROTO` to overwrite the latter.
+#[test]
+fn test_generated_code_is_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("proto");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    prost_build::Config::new()
+        .protoc_executable(protoc_bin_vendored::protoc_bin_path().unwrap())
+        .out_dir(&out_dir)
+        .compile_protos(
+            &[root.join("proto/leim/v1/lei.proto")],
+            &[root.join("proto")],
+        )
+        .unwrap();
+
+    let generated = std::fs::read_to_string(out_dir.join("leim.v1.rs")).unwrap();
+    if std::env::var_os("LEIM_UPDATE_PROTO").is_some() {
+        std::fs::write(root.join(GENERATED), &generated).unwrap();
+    }
+    assert!(
+        generated == std::fs::read_to_string(root.join(GENERATED)).unwrap(),
+        "{GENERATED} is out of date, rerun with LEIM_UPDATE_PROTO=1 to update it"
+    );
+}